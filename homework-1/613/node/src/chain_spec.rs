@@ -53,6 +53,11 @@ pub fn development_config() -> Result<ChainSpec, String> {
             get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
             get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
         ],
+        // Pre-seeded PoE claims
+        vec![(
+            b"hello".to_vec(),
+            get_account_id_from_seed::<sr25519::Public>("Alice"),
+        )],
         true,
     ))
     .build())
@@ -89,6 +94,11 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
             get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
             get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
         ],
+        // Pre-seeded PoE claims
+        vec![(
+            b"hello".to_vec(),
+            get_account_id_from_seed::<sr25519::Public>("Alice"),
+        )],
         true,
     ))
     .build())
@@ -99,6 +109,7 @@ fn testnet_genesis(
     initial_authorities: Vec<(AuraId, GrandpaId)>,
     root_key: AccountId,
     endowed_accounts: Vec<AccountId>,
+    initial_claims: Vec<(Vec<u8>, AccountId)>,
     _enable_println: bool,
 ) -> serde_json::Value {
     serde_json::json!({
@@ -116,5 +127,8 @@ fn testnet_genesis(
             // Assign network admin rights.
             "key": Some(root_key),
         },
+        "poeLegal": {
+            "claims": initial_claims,
+        },
     })
 }
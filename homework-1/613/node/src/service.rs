@@ -202,12 +202,19 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
     let rpc_extensions_builder = {
         let client = client.clone();
         let pool = transaction_pool.clone();
+        // Claim content is only written to the off-chain database with indexing enabled.
+        let offchain_storage = if config.offchain_worker.indexing_enabled {
+            backend.offchain_storage()
+        } else {
+            None
+        };
 
         Box::new(move |deny_unsafe, _| {
             let deps = crate::rpc::FullDeps {
                 client: client.clone(),
                 pool: pool.clone(),
                 deny_unsafe,
+                offchain_storage: offchain_storage.clone(),
             };
             crate::rpc::create_full(deps).map_err(Into::into)
         })
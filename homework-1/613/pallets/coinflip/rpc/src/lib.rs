@@ -0,0 +1,141 @@
+//! RPC interface for the coin flip pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_coinflip_runtime_api::{
+    CoinflipApi as CoinflipRuntimeApi, LeaderboardEntry, PlayerStats,
+};
+
+/// Balances are sent as decimal strings, so they only need to convert to and from strings.
+#[rpc(
+    client,
+    server,
+    client_bounds(
+        BlockHash: Serialize + Send + Sync + 'static,
+        AccountId: Serialize + DeserializeOwned + Send + Sync + 'static,
+        Balance: std::str::FromStr + Send + Sync + 'static
+    ),
+    server_bounds(
+        BlockHash: DeserializeOwned + Send + Sync + 'static,
+        AccountId: Serialize + DeserializeOwned + Send + Sync + 'static,
+        Balance: std::fmt::Display + Send + Sync + 'static
+    )
+)]
+pub trait CoinflipApi<BlockHash, AccountId, Balance> {
+    /// The games played and won by an account over all tables.
+    #[method(name = "coinflip_playerStats")]
+    fn player_stats(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<PlayerStats<Balance>>;
+
+    /// The games played and won by an account at a table.
+    #[method(name = "coinflip_tableStats")]
+    fn table_stats(
+        &self,
+        table_id: u32,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<PlayerStats<Balance>>;
+
+    /// The players who won the most, best first.
+    #[method(name = "coinflip_leaderboard")]
+    fn leaderboard(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<LeaderboardEntry<AccountId, Balance>>>;
+}
+
+/// Provides RPC methods to query the coin flip pallet.
+pub struct Coinflip<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Coinflip<C, Block> {
+    /// Creates a new instance of the Coinflip RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+fn runtime_error(e: impl std::fmt::Display) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Unable to query coin flip stats.",
+        Some(e.to_string()),
+    )
+}
+
+impl<C, Block, AccountId, Balance> CoinflipApiServer<<Block as BlockT>::Hash, AccountId, Balance>
+    for Coinflip<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: CoinflipRuntimeApi<Block, AccountId, Balance>,
+    AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    Balance: Codec + std::fmt::Display + Send + Sync + 'static,
+{
+    fn player_stats(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<PlayerStats<Balance>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.player_stats(at_hash, who).map_err(runtime_error)
+    }
+
+    fn table_stats(
+        &self,
+        table_id: u32,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<PlayerStats<Balance>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.table_stats(at_hash, table_id, who)
+            .map_err(runtime_error)
+    }
+
+    fn leaderboard(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<LeaderboardEntry<AccountId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.leaderboard(at_hash).map_err(runtime_error)
+    }
+}
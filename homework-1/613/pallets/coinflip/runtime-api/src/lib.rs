@@ -0,0 +1,89 @@
+//! Runtime API definition for the coin flip pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+
+/// The record of a player over all tables, or at a single one.
+///
+/// Balances are serialized as decimal strings, as JSON numbers cannot hold every `u128`.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "std",
+    serde(
+        rename_all = "camelCase",
+        bound(
+            serialize = "Balance: std::fmt::Display",
+            deserialize = "Balance: std::str::FromStr"
+        )
+    )
+)]
+pub struct PlayerStats<Balance> {
+    pub games_played: u32,
+    pub wins: u32,
+    #[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+    pub total_staked: Balance,
+    #[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+    pub total_won: Balance,
+}
+
+/// A player on the leaderboard and everything they have won.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "std",
+    serde(
+        rename_all = "camelCase",
+        bound(
+            serialize = "AccountId: serde::Serialize, Balance: std::fmt::Display",
+            deserialize = "AccountId: serde::Deserialize<'de>, Balance: std::str::FromStr"
+        )
+    )
+)]
+pub struct LeaderboardEntry<AccountId, Balance> {
+    pub account: AccountId,
+    #[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+    pub total_won: Balance,
+}
+
+#[cfg(feature = "std")]
+mod serde_balance {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer, T: std::fmt::Display>(
+        t: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&t.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, T: std::str::FromStr>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse::<T>()
+            .map_err(|_| serde::de::Error::custom("Parse from string failed"))
+    }
+}
+
+sp_api::decl_runtime_apis! {
+    /// Queries of the coin flip stats.
+    pub trait CoinflipApi<AccountId, Balance>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// The games played and won by `who` over all tables.
+        fn player_stats(who: AccountId) -> PlayerStats<Balance>;
+
+        /// The games played and won by `who` at a table, including closed ones.
+        fn table_stats(table_id: u32, who: AccountId) -> PlayerStats<Balance>;
+
+        /// The players who won the most, best first.
+        fn leaderboard() -> Vec<LeaderboardEntry<AccountId, Balance>>;
+    }
+}
@@ -0,0 +1,249 @@
+//! Benchmarking setup for pallet-coinflip
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Coinflip;
+use frame_benchmarking::v2::*;
+use frame_support::traits::fungible::{Inspect, Mutate};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::{
+    traits::{Hash, One, Saturating},
+    Permill,
+};
+
+const SEED: u32 = 0;
+
+fn entry_fee<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance().saturating_mul(10u32.into())
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who = account(name, index, SEED);
+    let _ = T::Currency::set_balance(&who, entry_fee::<T>().saturating_mul(1_000u32.into()));
+    who
+}
+
+/// Open a table with a house cut and room in its pot, so that payments are split between the pot
+/// and the owner.
+fn open_table<T: Config>() -> TableId {
+    let owner = funded_account::<T>("owner", 0);
+    let fee = entry_fee::<T>();
+    let table_id = NextTableId::<T>::get();
+    Coinflip::<T>::create_table(
+        RawOrigin::Signed(owner).into(),
+        fee,
+        fee.saturating_mul(10u32.into()),
+        Permill::from_percent(10),
+    )
+    .unwrap();
+    table_id
+}
+
+/// Fill the leaderboard with players who won less than anyone in the benchmarks, so that a win
+/// ranks first and shifts all of them.
+fn fill_leaderboard<T: Config>() {
+    Leaderboard::<T>::mutate(|board| {
+        for i in 0..T::MaxLeaderboardLength::get() {
+            let _ = board.try_push((account("leader", i, SEED), One::one()));
+        }
+    });
+}
+
+fn house_seed<T: Config>() -> T::Hash {
+    T::Hashing::hash_of(b"house seed")
+}
+
+fn set_up_house_commitment<T: Config>() -> Result<(), BenchmarkError> {
+    let origin = T::HouseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    Coinflip::<T>::commit_house_seed(origin, T::Hashing::hash_of(&house_seed::<T>()))?;
+    Ok(())
+}
+
+/// Close the house commitment to new players and move on to the block its seed can be revealed
+/// in.
+fn close_house_commitment_and_wait<T: Config>() -> Result<(), BenchmarkError> {
+    let origin = T::HouseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    Coinflip::<T>::close_house_commitment(origin)?;
+    advance_by::<T>(One::one());
+    Ok(())
+}
+
+fn reveal_house_commitment<T: Config>() -> Result<(), BenchmarkError> {
+    close_house_commitment_and_wait::<T>()?;
+    let origin = T::HouseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    Coinflip::<T>::reveal_house_seed(origin, house_seed::<T>())?;
+    Ok(())
+}
+
+/// Commit a bet of `player` at a table that wins against the house seed, returning its choice
+/// and salt.
+fn commit_winning_bet<T: Config>(
+    player: &T::AccountId,
+    table_id: TableId,
+) -> Result<(bool, T::Hash), BenchmarkError> {
+    let salt = T::Hashing::hash_of(b"salt");
+    let choice = Coinflip::<T>::is_heads(&house_seed::<T>(), &salt);
+    let commitment = T::Hashing::hash_of(&(player, table_id, choice, salt));
+    Coinflip::<T>::commit(
+        RawOrigin::Signed(player.clone()).into(),
+        table_id,
+        commitment,
+    )?;
+    Ok((choice, salt))
+}
+
+fn advance_by<T: Config>(blocks: BlockNumberFor<T>) {
+    let now = frame_system::Pallet::<T>::block_number();
+    frame_system::Pallet::<T>::set_block_number(now.saturating_add(blocks));
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_table() {
+        let caller = funded_account::<T>("caller", 0);
+        let fee = entry_fee::<T>();
+        let table_id = NextTableId::<T>::get();
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            fee,
+            fee,
+            Permill::from_percent(10),
+        );
+
+        assert_eq!(
+            Tables::<T>::get(table_id).map(|table| table.owner),
+            Some(caller)
+        );
+    }
+
+    #[benchmark]
+    fn play() -> Result<(), BenchmarkError> {
+        let table_id = open_table::<T>();
+        fill_leaderboard::<T>();
+        let caller = funded_account::<T>("caller", 0);
+        // A win pays out the pot and ranks the caller, so steer the randomness source to one.
+        let mut attempt = 0;
+        while !Coinflip::<T>::random_flip() {
+            frame_support::ensure!(
+                attempt < 256,
+                BenchmarkError::Stop("the benchmark helper cannot make `play` win")
+            );
+            T::BenchmarkHelper::reseed(attempt);
+            attempt += 1;
+        }
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), table_id);
+
+        assert_eq!(Stats::<T>::get(&caller).wins, 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn commit_house_seed() -> Result<(), BenchmarkError> {
+        let origin =
+            T::HouseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let commitment = T::Hashing::hash_of(&house_seed::<T>());
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, commitment);
+
+        assert_eq!(HouseCommitment::<T>::get(), Some(commitment));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reveal_house_seed() -> Result<(), BenchmarkError> {
+        let origin =
+            T::HouseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let table_id = open_table::<T>();
+        set_up_house_commitment::<T>()?;
+        // A player committed against the seed, so that it is stored.
+        commit_winning_bet::<T>(&funded_account::<T>("player", 0), table_id)?;
+        close_house_commitment_and_wait::<T>()?;
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, house_seed::<T>());
+
+        assert!(HouseSeeds::<T>::contains_key(T::Hashing::hash_of(
+            &house_seed::<T>()
+        )));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn commit() -> Result<(), BenchmarkError> {
+        let table_id = open_table::<T>();
+        set_up_house_commitment::<T>()?;
+        let caller = funded_account::<T>("caller", 0);
+        let commitment = T::Hashing::hash_of(b"commitment");
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), table_id, commitment);
+
+        assert!(Commitments::<T>::contains_key(table_id, &caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reveal() -> Result<(), BenchmarkError> {
+        let table_id = open_table::<T>();
+        fill_leaderboard::<T>();
+        set_up_house_commitment::<T>()?;
+        let caller = funded_account::<T>("caller", 0);
+        let (choice, salt) = commit_winning_bet::<T>(&caller, table_id)?;
+        reveal_house_commitment::<T>()?;
+        advance_by::<T>(T::RevealDelay::get());
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), table_id, choice, salt);
+
+        assert_eq!(Stats::<T>::get(&caller).wins, 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn forfeit() -> Result<(), BenchmarkError> {
+        let table_id = open_table::<T>();
+        set_up_house_commitment::<T>()?;
+        let player = funded_account::<T>("player", 0);
+        commit_winning_bet::<T>(&player, table_id)?;
+        reveal_house_commitment::<T>()?;
+        advance_by::<T>(
+            T::RevealDelay::get()
+                .saturating_add(T::RevealPeriod::get())
+                .saturating_add(One::one()),
+        );
+        let caller = funded_account::<T>("caller", 0);
+        // The house revealed in time, so the stake is paid in rather than refunded.
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), table_id, player.clone());
+
+        assert_eq!(Stats::<T>::get(&player).games_played, 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn close_table() {
+        let table_id = open_table::<T>();
+        let owner = Tables::<T>::get(table_id).unwrap().owner;
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), table_id);
+
+        assert!(!Tables::<T>::contains_key(table_id));
+    }
+
+    #[benchmark]
+    fn close_house_commitment() -> Result<(), BenchmarkError> {
+        let origin =
+            T::HouseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        set_up_house_commitment::<T>()?;
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin);
+
+        assert_eq!(HouseCommitment::<T>::get(), None);
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Coinflip, crate::mock::new_test_ext(), crate::mock::Test);
+}
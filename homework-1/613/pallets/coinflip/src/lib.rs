@@ -0,0 +1,770 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// A FRAME pallet for coin flip game
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+/// Steers the randomness source in the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+    /// Change the randomness `play` sees, differently for every `attempt`.
+    fn reseed(attempt: u32);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper for () {
+    fn reseed(_attempt: u32) {}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::WeightInfo;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{
+            fungible::{Inspect, Mutate, MutateHold},
+            tokens::{Fortitude, Precision, Preservation},
+            Randomness,
+        },
+        PalletId,
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::{
+        traits::{AccountIdConversion, Hash, Saturating, Zero},
+        Permill,
+    };
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// The identifier of a coin flip table.
+    pub type TableId = u32;
+
+    /// A coin flip table and its rules.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct TableInfo<AccountId, Balance> {
+        /// The account that created the table, collects the house cut and may close it.
+        pub owner: AccountId,
+        /// The payment for every game at the table.
+        pub entry_fee: Balance,
+        /// The most the pot can grow to. Payments beyond it go to the owner.
+        pub max_pot: Balance,
+        /// The share of every payment that goes to the owner instead of the pot.
+        pub house_cut: Permill,
+        /// The number of commit-reveal bets at the table that are not settled yet.
+        pub commitments: u32,
+    }
+
+    pub type TableInfoOf<T> = TableInfo<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+    /// A player's hidden bet in the commit-reveal mode.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Commitment<Hash, BlockNumber, Balance> {
+        /// `hash((player, table, choice, salt))`, where `choice` is `true` for heads.
+        pub hash: Hash,
+        /// The house commitment whose seed decides the flip.
+        pub house_commitment: Hash,
+        /// The held payment.
+        pub stake: Balance,
+        /// The block the bet was committed in.
+        pub block_number: BlockNumber,
+    }
+
+    pub type CommitmentOf<T> =
+        Commitment<<T as frame_system::Config>::Hash, BlockNumberFor<T>, BalanceOf<T>>;
+
+    /// The record of a player over all tables, or at a single one.
+    #[derive(
+        Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+    )]
+    pub struct PlayerStats<Balance> {
+        /// The number of games played, including forfeited commit-reveal bets.
+        pub games_played: u32,
+        /// The number of games won.
+        pub wins: u32,
+        /// The sum of all payments.
+        pub total_staked: Balance,
+        /// The sum of all winnings.
+        pub total_won: Balance,
+    }
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// The currency games are paid in.
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// The coin flip's id, used for deriving the accounts holding the pots.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// The origin allowed to commit and reveal the house seed.
+        type HouseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The number of blocks after a commitment before it can be revealed.
+        #[pallet::constant]
+        type RevealDelay: Get<BlockNumberFor<Self>>;
+
+        /// The number of blocks a commitment can be revealed in, after which it is forfeited.
+        #[pallet::constant]
+        type RevealPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The number of players ranked on the leaderboard.
+        #[pallet::constant]
+        type MaxLeaderboardLength: Get<u32>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+
+        /// Makes `play` win in the benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: crate::BenchmarkHelper;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// A reason for the pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// The stake of a committed bet.
+        #[codec(index = 0)]
+        Stake,
+    }
+
+    /// The id the next table will be created with.
+    #[pallet::storage]
+    #[pallet::getter(fn next_table_id)]
+    pub type NextTableId<T> = StorageValue<_, TableId, ValueQuery>;
+
+    /// The open tables.
+    #[pallet::storage]
+    #[pallet::getter(fn tables)]
+    pub type Tables<T: Config> = StorageMap<_, Twox64Concat, TableId, TableInfoOf<T>>;
+
+    /// The jackpot of each table, mirroring the balance of its pot account after every game.
+    #[pallet::storage]
+    #[pallet::getter(fn pot)]
+    pub type Pot<T: Config> = StorageMap<_, Twox64Concat, TableId, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn nonce)]
+    pub type Nonce<T> = StorageValue<_, u64, ValueQuery>;
+
+    /// The hash of the house seed that new commitments are played against.
+    #[pallet::storage]
+    #[pallet::getter(fn house_commitment)]
+    pub type HouseCommitment<T: Config> = StorageValue<_, T::Hash>;
+
+    /// The house commitment closed to new players and the block it was closed in. Its seed can
+    /// be revealed from the next block on, so no commitment can be made against a seed revealed
+    /// in the same block.
+    #[pallet::storage]
+    #[pallet::getter(fn closed_house_commitment)]
+    pub type ClosedHouseCommitment<T: Config> = StorageValue<_, (T::Hash, BlockNumberFor<T>)>;
+
+    /// The revealed house seeds and the blocks they were revealed in, by the commitment they
+    /// were revealed for. A seed is kept only while player commitments refer to it.
+    #[pallet::storage]
+    #[pallet::getter(fn house_seeds)]
+    pub type HouseSeeds<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Hash, (T::Hash, BlockNumberFor<T>)>;
+
+    /// The number of pending player commitments made against each house commitment.
+    #[pallet::storage]
+    #[pallet::getter(fn house_commitment_refs)]
+    pub type HouseCommitmentRefs<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Hash, u32, ValueQuery>;
+
+    /// The pending commitment of each player, by table.
+    #[pallet::storage]
+    #[pallet::getter(fn commitments)]
+    pub type Commitments<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, TableId, Blake2_128Concat, T::AccountId, CommitmentOf<T>>;
+
+    /// The record of every player who has played.
+    #[pallet::storage]
+    #[pallet::getter(fn stats)]
+    pub type Stats<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, PlayerStats<BalanceOf<T>>, ValueQuery>;
+
+    /// The record of every player at each table they played at. It is kept after the table
+    /// closes.
+    #[pallet::storage]
+    #[pallet::getter(fn table_stats)]
+    pub type TableStats<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        TableId,
+        Blake2_128Concat,
+        T::AccountId,
+        PlayerStats<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    /// The players who won the most, with their total winnings, best first. Players who reach
+    /// the same total later rank lower.
+    #[pallet::storage]
+    #[pallet::getter(fn leaderboard)]
+    pub type Leaderboard<T: Config> = StorageValue<
+        _,
+        BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxLeaderboardLength>,
+        ValueQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// \[table, owner, entry_fee\]
+        TableCreated(TableId, T::AccountId, BalanceOf<T>),
+        /// The owner closed a table and got its pot back. \[table, pot\]
+        TableClosed(TableId, BalanceOf<T>),
+        /// \[table, player, winnings\]
+        PlayResult(TableId, T::AccountId, BalanceOf<T>),
+        /// \[commitment\]
+        HouseCommitted(T::Hash),
+        /// The house stopped taking commitments against its seed. \[commitment\]
+        HouseClosed(T::Hash),
+        /// \[commitment, seed\]
+        HouseRevealed(T::Hash, T::Hash),
+        /// \[table, player, commitment\]
+        Committed(TableId, T::AccountId, T::Hash),
+        /// A player failed to reveal in time and lost the stake to the table.
+        /// \[table, player, stake\]
+        Forfeited(TableId, T::AccountId, BalanceOf<T>),
+        /// The house failed to reveal its seed in time, so the stake was returned.
+        /// \[table, player, stake\]
+        Refunded(TableId, T::AccountId, BalanceOf<T>),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// There is no open table with this id.
+        UnknownTable,
+        /// The entry fee is below the existential deposit, so it could not keep the pot account
+        /// alive.
+        EntryFeeTooLow,
+        /// The maximum pot is below the entry fee the pot is seeded with.
+        MaxPotTooLow,
+        /// All table ids are taken.
+        TableIdsExhausted,
+        /// Only the owner of the table can do this.
+        NotTableOwner,
+        /// The table has commit-reveal bets that are not settled yet.
+        PendingCommitments,
+        /// The house has a commitment that is not revealed yet.
+        HouseCommitmentPending,
+        /// There is no house commitment to play against, close or reveal.
+        NoHouseCommitment,
+        /// The house commitment must be closed to new players before its seed is revealed.
+        HouseCommitmentOpen,
+        /// The seed or choice does not match its commitment.
+        InvalidReveal,
+        /// The player already has a pending commitment at the table.
+        AlreadyCommitted,
+        /// The player has no pending commitment at the table.
+        NoCommitment,
+        /// The reveal window has not opened yet.
+        TooEarly,
+        /// The reveal window has closed.
+        TooLate,
+        /// The house has not revealed the seed this commitment is played against.
+        HouseSeedNotRevealed,
+        /// The reveal window is still open.
+        RevealWindowOpen,
+        /// The pot holds more than the entry fee the owner seeded it with. The rest was paid in
+        /// by players and is owed to the next winner.
+        PotOwedToPlayers,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Open a table where every game costs `entry_fee`.
+        ///
+        /// The caller owns the table and seeds its jackpot with one entry fee. `house_cut` of
+        /// every payment, and whatever would grow the pot beyond `max_pot`, goes to the owner.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_table())]
+        pub fn create_table(
+            origin: OriginFor<T>,
+            entry_fee: BalanceOf<T>,
+            max_pot: BalanceOf<T>,
+            house_cut: Permill,
+        ) -> DispatchResult {
+            // Ensure the function call is a signed message (i.e. a transaction)
+            let sender = ensure_signed(origin)?;
+
+            ensure!(
+                entry_fee >= T::Currency::minimum_balance(),
+                Error::<T>::EntryFeeTooLow
+            );
+            ensure!(max_pot >= entry_fee, Error::<T>::MaxPotTooLow);
+
+            let table_id = Self::next_table_id();
+            let next_id = table_id
+                .checked_add(1)
+                .ok_or(Error::<T>::TableIdsExhausted)?;
+
+            // Initialize jackpot;
+            T::Currency::transfer(
+                &sender,
+                &Self::account_id(table_id),
+                entry_fee,
+                Preservation::Preserve,
+            )?;
+
+            NextTableId::<T>::put(next_id);
+            Tables::<T>::insert(
+                table_id,
+                TableInfo {
+                    owner: sender.clone(),
+                    entry_fee,
+                    max_pot,
+                    house_cut,
+                    commitments: 0,
+                },
+            );
+            Pot::<T>::insert(table_id, Self::pot_balance(table_id));
+
+            Self::deposit_event(Event::TableCreated(table_id, sender, entry_fee));
+            Ok(())
+        }
+
+        /// This function allow a user to play our coin flip game at a table
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::play())]
+        pub fn play(origin: OriginFor<T>, table_id: TableId) -> DispatchResult {
+            // Ensure that the function call is a signed message (i.e. a transaction)
+            let sender = ensure_signed(origin)?;
+
+            // Ensure the table is open
+            let table = Self::tables(table_id).ok_or(Error::<T>::UnknownTable)?;
+
+            // Read our storage values, and place them in memory variables
+            let mut nonce = Self::nonce();
+
+            let winnings = Self::settle(
+                table_id,
+                &table,
+                &sender,
+                table.entry_fee,
+                Self::random_flip(),
+            )?;
+
+            // Increase the nonce
+            nonce = nonce.wrapping_add(1);
+            Nonce::<T>::put(nonce);
+
+            // Raise event for the play result
+            Self::deposit_event(Event::PlayResult(table_id, sender, winnings));
+
+            Ok(())
+        }
+
+        /// Commit to the hash of the house seed for the next commit-reveal games.
+        ///
+        /// The previous seed must have been revealed first.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::commit_house_seed())]
+        pub fn commit_house_seed(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+            T::HouseOrigin::ensure_origin(origin)?;
+            ensure!(
+                Self::house_commitment().is_none() && Self::closed_house_commitment().is_none(),
+                Error::<T>::HouseCommitmentPending
+            );
+
+            HouseCommitment::<T>::put(commitment);
+
+            Self::deposit_event(Event::HouseCommitted(commitment));
+            Ok(())
+        }
+
+        /// Reveal the house seed, letting the players who committed against it reveal.
+        ///
+        /// The commitment must have been closed with `close_house_commitment` in an earlier
+        /// block.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::reveal_house_seed())]
+        pub fn reveal_house_seed(origin: OriginFor<T>, seed: T::Hash) -> DispatchResult {
+            T::HouseOrigin::ensure_origin(origin)?;
+            ensure!(
+                Self::house_commitment().is_none(),
+                Error::<T>::HouseCommitmentOpen
+            );
+            let (commitment, closed_at) =
+                Self::closed_house_commitment().ok_or(Error::<T>::NoHouseCommitment)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > closed_at,
+                Error::<T>::TooEarly
+            );
+            ensure!(
+                T::Hashing::hash_of(&seed) == commitment,
+                Error::<T>::InvalidReveal
+            );
+
+            ClosedHouseCommitment::<T>::kill();
+            // Nobody needs the seed if nobody played against it.
+            if Self::house_commitment_refs(commitment) > 0 {
+                HouseSeeds::<T>::insert(
+                    commitment,
+                    (seed, frame_system::Pallet::<T>::block_number()),
+                );
+            }
+
+            Self::deposit_event(Event::HouseRevealed(commitment, seed));
+            Ok(())
+        }
+
+        /// Bet on a coin flip at a table without revealing the choice, holding the entry fee as
+        /// the stake.
+        ///
+        /// `commitment` is `hash((player, table_id, choice, salt))`, with `choice` being `true`
+        /// for heads, so that it cannot be copied by other players or to other tables. It must be
+        /// revealed with `reveal` between `RevealDelay` and `RevealDelay + RevealPeriod` blocks
+        /// from now.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::commit())]
+        pub fn commit(
+            origin: OriginFor<T>,
+            table_id: TableId,
+            commitment: T::Hash,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let mut table = Self::tables(table_id).ok_or(Error::<T>::UnknownTable)?;
+            let house_commitment = Self::house_commitment().ok_or(Error::<T>::NoHouseCommitment)?;
+            ensure!(
+                !Commitments::<T>::contains_key(table_id, &sender),
+                Error::<T>::AlreadyCommitted
+            );
+
+            let stake = table.entry_fee;
+            T::Currency::hold(&HoldReason::Stake.into(), &sender, stake)?;
+            Commitments::<T>::insert(
+                table_id,
+                &sender,
+                Commitment {
+                    hash: commitment,
+                    house_commitment,
+                    stake,
+                    block_number: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+            table.commitments.saturating_inc();
+            Tables::<T>::insert(table_id, table);
+            HouseCommitmentRefs::<T>::mutate(house_commitment, |refs| refs.saturating_inc());
+
+            Self::deposit_event(Event::Committed(table_id, sender, commitment));
+            Ok(())
+        }
+
+        /// Reveal a committed bet and settle it like `play`, flipping the coin with the house
+        /// seed and the player's salt.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::reveal())]
+        pub fn reveal(
+            origin: OriginFor<T>,
+            table_id: TableId,
+            choice: bool,
+            salt: T::Hash,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let commitment =
+                Self::commitments(table_id, &sender).ok_or(Error::<T>::NoCommitment)?;
+            let opens_at = commitment
+                .block_number
+                .saturating_add(T::RevealDelay::get());
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now >= opens_at, Error::<T>::TooEarly);
+            ensure!(
+                now <= opens_at.saturating_add(T::RevealPeriod::get()),
+                Error::<T>::TooLate
+            );
+            ensure!(
+                T::Hashing::hash_of(&(&sender, table_id, choice, salt)) == commitment.hash,
+                Error::<T>::InvalidReveal
+            );
+            let (house_seed, _) = Self::house_seeds(commitment.house_commitment)
+                .ok_or(Error::<T>::HouseSeedNotRevealed)?;
+
+            let table = Self::remove_commitment(table_id, &sender)?;
+            T::Currency::release(
+                &HoldReason::Stake.into(),
+                &sender,
+                commitment.stake,
+                Precision::Exact,
+            )?;
+            let won = choice == Self::is_heads(&house_seed, &salt);
+            let winnings = Self::settle(table_id, &table, &sender, commitment.stake, won)?;
+
+            Self::deposit_event(Event::PlayResult(table_id, sender, winnings));
+            Ok(())
+        }
+
+        /// Settle the commitment of a `player` at a table who did not reveal in time.
+        ///
+        /// The stake is paid in like a lost game, unless the house did not reveal its seed
+        /// before the reveal window closed, in which case the player could not reveal and gets
+        /// it back.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::forfeit())]
+        pub fn forfeit(
+            origin: OriginFor<T>,
+            table_id: TableId,
+            player: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let commitment =
+                Self::commitments(table_id, &player).ok_or(Error::<T>::NoCommitment)?;
+            let closes_at = commitment
+                .block_number
+                .saturating_add(T::RevealDelay::get())
+                .saturating_add(T::RevealPeriod::get());
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > closes_at,
+                Error::<T>::RevealWindowOpen
+            );
+            let house_revealed_in_time = Self::house_seeds(commitment.house_commitment)
+                .is_some_and(|(_, revealed_at)| revealed_at <= closes_at);
+
+            let table = Self::remove_commitment(table_id, &player)?;
+            T::Currency::release(
+                &HoldReason::Stake.into(),
+                &player,
+                commitment.stake,
+                Precision::Exact,
+            )?;
+            if house_revealed_in_time {
+                Self::pay_in(
+                    table_id,
+                    &table,
+                    &player,
+                    commitment.stake,
+                    Preservation::Expendable,
+                )?;
+                Pot::<T>::insert(table_id, Self::pot_balance(table_id));
+                Self::record_game(table_id, &player, commitment.stake, false, Zero::zero());
+
+                Self::deposit_event(Event::Forfeited(table_id, player, commitment.stake));
+            } else {
+                Self::deposit_event(Event::Refunded(table_id, player, commitment.stake));
+            }
+
+            Ok(())
+        }
+
+        /// Close a table, paying its pot back to the owner.
+        ///
+        /// The owner only gets back what they funded the pot with, so the pot must be down to at
+        /// most one entry fee, as it is after every win. Until then the owner can play for the
+        /// jackpot like anyone else. All commit-reveal bets at the table must be revealed or
+        /// forfeited first.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::close_table())]
+        pub fn close_table(origin: OriginFor<T>, table_id: TableId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let table = Self::tables(table_id).ok_or(Error::<T>::UnknownTable)?;
+            ensure!(table.owner == sender, Error::<T>::NotTableOwner);
+            ensure!(table.commitments == 0, Error::<T>::PendingCommitments);
+
+            let pot_account = Self::account_id(table_id);
+            let pot = T::Currency::balance(&pot_account);
+            ensure!(pot <= table.entry_fee, Error::<T>::PotOwedToPlayers);
+            T::Currency::transfer(&pot_account, &sender, pot, Preservation::Expendable)?;
+            Tables::<T>::remove(table_id);
+            Pot::<T>::remove(table_id);
+
+            Self::deposit_event(Event::TableClosed(table_id, pot));
+            Ok(())
+        }
+
+        /// Stop taking commitments against the house seed, so that it can be revealed from the
+        /// next block on.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::close_house_commitment())]
+        pub fn close_house_commitment(origin: OriginFor<T>) -> DispatchResult {
+            T::HouseOrigin::ensure_origin(origin)?;
+            let commitment = HouseCommitment::<T>::take().ok_or(Error::<T>::NoHouseCommitment)?;
+
+            ClosedHouseCommitment::<T>::put((
+                commitment,
+                frame_system::Pallet::<T>::block_number(),
+            ));
+
+            Self::deposit_event(Event::HouseClosed(commitment));
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The account holding the pot of a table.
+        pub fn account_id(table_id: TableId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(table_id)
+        }
+
+        /// The balance of the pot account of a table, which is its jackpot.
+        fn pot_balance(table_id: TableId) -> BalanceOf<T> {
+            T::Currency::balance(&Self::account_id(table_id))
+        }
+
+        /// Whether the player of `play` wins, according to the randomness source.
+        pub(crate) fn random_flip() -> bool {
+            // Generate a random seed using the configured randomness source
+            let random_seed = T::Randomness::random_seed()
+                .0
+                .using_encoded(T::Hashing::hash);
+            // as_ref returns an array of u8
+            let seed_arr = random_seed.as_ref();
+            seed_arr[seed_arr.len() - 1] < 128
+        }
+
+        /// Whether the commit-reveal coin lands on heads for the given house seed and player
+        /// salt.
+        pub fn is_heads(house_seed: &T::Hash, salt: &T::Hash) -> bool {
+            let flip = T::Hashing::hash_of(&(house_seed, salt));
+            let flip = flip.as_ref();
+            flip[flip.len() - 1] < 128
+        }
+
+        /// Remove the commitment of `player` at a table, returning the table with its count of
+        /// pending commitments updated.
+        ///
+        /// The house seed the commitment was played against is pruned once no other commitment
+        /// refers to it.
+        fn remove_commitment(
+            table_id: TableId,
+            player: &T::AccountId,
+        ) -> Result<TableInfoOf<T>, DispatchError> {
+            if let Some(commitment) = Commitments::<T>::take(table_id, player) {
+                let house_commitment = commitment.house_commitment;
+                let refs = HouseCommitmentRefs::<T>::mutate(house_commitment, |refs| {
+                    refs.saturating_dec();
+                    *refs
+                });
+                if refs == 0 {
+                    HouseCommitmentRefs::<T>::remove(house_commitment);
+                    HouseSeeds::<T>::remove(house_commitment);
+                }
+            }
+            Tables::<T>::try_mutate(table_id, |table| {
+                let table = table.as_mut().ok_or(Error::<T>::UnknownTable)?;
+                table.commitments.saturating_dec();
+                Ok(table.clone())
+            })
+        }
+
+        /// Split the `payment` of `player` between the pot of a table and its owner, according
+        /// to the house cut and the maximum pot.
+        fn pay_in(
+            table_id: TableId,
+            table: &TableInfoOf<T>,
+            player: &T::AccountId,
+            payment: BalanceOf<T>,
+            preservation: Preservation,
+        ) -> DispatchResult {
+            let room = table.max_pot.saturating_sub(Self::pot_balance(table_id));
+            let to_pot = payment
+                .saturating_sub(table.house_cut.mul_floor(payment))
+                .min(room);
+            let to_owner = payment.saturating_sub(to_pot);
+
+            if !to_pot.is_zero() {
+                T::Currency::transfer(player, &Self::account_id(table_id), to_pot, preservation)?;
+            }
+            if !to_owner.is_zero() {
+                T::Currency::transfer(player, &table.owner, to_owner, preservation)?;
+            }
+            Ok(())
+        }
+
+        /// Pay the `payment` of `player` in at a table and pay out the jackpot if they `won`,
+        /// returning the winnings.
+        ///
+        /// The pot is read from the pot account, so anything sent there directly is part of the
+        /// jackpot.
+        fn settle(
+            table_id: TableId,
+            table: &TableInfoOf<T>,
+            player: &T::AccountId,
+            payment: BalanceOf<T>,
+            won: bool,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let pot = Self::pot_balance(table_id);
+            let pot_account = Self::account_id(table_id);
+
+            // Try to pay in, making sure that it will not kill the account
+            Self::pay_in(table_id, table, player, payment, Preservation::Preserve)?;
+
+            let mut winnings = Zero::zero();
+            if won {
+                // If the user won the coin flip, pay out the pot as it was before this game, as
+                // far as that keeps the pot account alive.
+                winnings = pot.min(T::Currency::reducible_balance(
+                    &pot_account,
+                    Preservation::Preserve,
+                    Fortitude::Polite,
+                ));
+                T::Currency::transfer(&pot_account, player, winnings, Preservation::Preserve)?;
+            }
+
+            Pot::<T>::insert(table_id, Self::pot_balance(table_id));
+            Self::record_game(table_id, player, payment, won, winnings);
+            Ok(winnings)
+        }
+
+        /// Add a game at a table to the stats of `player`, moving them up the leaderboard if
+        /// they won something.
+        fn record_game(
+            table_id: TableId,
+            player: &T::AccountId,
+            payment: BalanceOf<T>,
+            won: bool,
+            winnings: BalanceOf<T>,
+        ) {
+            let record = |stats: &mut PlayerStats<BalanceOf<T>>| {
+                stats.games_played.saturating_inc();
+                if won {
+                    stats.wins.saturating_inc();
+                }
+                stats.total_staked.saturating_accrue(payment);
+                stats.total_won.saturating_accrue(winnings);
+            };
+            TableStats::<T>::mutate(table_id, player, record);
+            let total_won = Stats::<T>::mutate(player, |stats| {
+                record(stats);
+                stats.total_won
+            });
+            if winnings.is_zero() {
+                return;
+            }
+
+            Leaderboard::<T>::mutate(|board| {
+                board.retain(|(who, _)| who != player);
+                let rank = board
+                    .iter()
+                    .position(|(_, won)| *won < total_won)
+                    .unwrap_or(board.len());
+                // Drops the last player if the board is full, or nothing if `player` ranks below
+                // all of them.
+                let _ = board.force_insert_keep_left(rank, (player.clone(), total_won));
+            });
+        }
+    }
+}
@@ -0,0 +1,131 @@
+// Creating mock runtime here
+
+use crate as pallet_coinflip;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU16, ConstU32, ConstU64, Randomness},
+    PalletId,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        CoinFlipModule: pallet_coinflip,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeTask = RuntimeTask;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ();
+    type MaxReserves = ConstU32<2>;
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+}
+
+impl Randomness<H256, BlockNumberFor<Test>> for CoinFlipModule {
+    fn random(_subject: &[u8]) -> (H256, BlockNumberFor<Test>) {
+        let output = match Self::nonce() {
+            0 => H256::from_low_u64_be(101),
+            1 => H256::from_low_u64_be(150),
+            _ => panic!("do not use other value except 1,2 for nonce in test cases"),
+        };
+        (output, 0)
+    }
+}
+
+parameter_types! {
+    pub const CoinFlipPalletId: PalletId = PalletId(*b"py/cflip");
+}
+
+impl pallet_coinflip::Config for Test {
+    type Randomness = CoinFlipModule;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type PalletId = CoinFlipPalletId;
+    type HouseOrigin = frame_system::EnsureRoot<u64>;
+    type RevealDelay = ConstU64<1>;
+    type RevealPeriod = ConstU64<5>;
+    type MaxLeaderboardLength = ConstU32<2>;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = CoinFlipBenchmarkHelper;
+}
+
+/// Switches the mock randomness between a win and a loss.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CoinFlipBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_coinflip::BenchmarkHelper for CoinFlipBenchmarkHelper {
+    fn reseed(attempt: u32) {
+        pallet_coinflip::Nonce::<Test>::put(u64::from(attempt % 2));
+    }
+}
+
+// This function basically just builds a genesis storage key/value store according to
+// our desired mockup.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 1000), (2, 20)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    t.into()
+}
@@ -0,0 +1,777 @@
+// Tests to be written here
+
+use super::*;
+use crate::{mock::*, Error};
+use frame_support::traits::fungible::{Inspect, InspectHold, Mutate};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use sp_runtime::traits::{BadOrigin, BlakeTwo256, Hash};
+use sp_runtime::{Permill, TokenError};
+
+const TABLE: TableId = 0;
+
+// Open a table owned by account 1, without a house cut or a reachable maximum pot.
+fn open_table(entry_fee: u64) -> TableId {
+    let table_id = CoinFlipModule::next_table_id();
+    assert_ok!(CoinFlipModule::create_table(
+        RuntimeOrigin::signed(1),
+        entry_fee,
+        1000,
+        Permill::zero()
+    ));
+    table_id
+}
+
+// Fund the pot account of a table and record the matching jackpot.
+fn seed_pot(table_id: TableId, amount: u64) {
+    Balances::set_balance(&CoinFlipModule::account_id(table_id), amount);
+    <Pot<Test>>::insert(table_id, amount);
+}
+
+#[test]
+fn create_table_should_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        // asserting the function can be called successfully
+        assert_ok!(CoinFlipModule::create_table(
+            RuntimeOrigin::signed(1),
+            100,
+            500,
+            Permill::from_percent(10)
+        ));
+        // asserting that the stored value is equal to what we stored
+        assert_eq!(
+            CoinFlipModule::tables(TABLE),
+            Some(TableInfo {
+                owner: 1,
+                entry_fee: 100,
+                max_pot: 500,
+                house_cut: Permill::from_percent(10),
+                commitments: 0,
+            })
+        );
+        assert_eq!(CoinFlipModule::pot(TABLE), 100);
+        System::assert_last_event(Event::<Test>::TableCreated(TABLE, 1, 100).into());
+        // the caller seeded the pot
+        assert_eq!(Balances::total_balance(&1), 900);
+        assert_eq!(
+            Balances::total_balance(&CoinFlipModule::account_id(TABLE)),
+            100
+        );
+
+        // anybody can open another table, with a pot of its own
+        assert_ok!(CoinFlipModule::create_table(
+            RuntimeOrigin::signed(2),
+            5,
+            5,
+            Permill::zero()
+        ));
+        assert_eq!(CoinFlipModule::next_table_id(), 2);
+        assert_eq!(CoinFlipModule::tables(1).map(|table| table.owner), Some(2));
+        assert_eq!(CoinFlipModule::pot(1), 5);
+        assert_eq!(CoinFlipModule::pot(TABLE), 100);
+        assert_eq!(Balances::total_balance(&CoinFlipModule::account_id(1)), 5);
+    });
+}
+
+#[test]
+fn create_table_with_invalid_rules_should_fail() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CoinFlipModule::create_table(RuntimeOrigin::signed(1), 0, 10, Permill::zero()),
+            Error::<Test>::EntryFeeTooLow
+        );
+        assert_noop!(
+            CoinFlipModule::create_table(RuntimeOrigin::signed(1), 10, 9, Permill::zero()),
+            Error::<Test>::MaxPotTooLow
+        );
+    });
+}
+
+#[test]
+fn play_security_check_should_work() {
+    new_test_ext().execute_with(|| {
+        // Test ensure_signed
+        assert_noop!(
+            CoinFlipModule::play(RuntimeOrigin::root(), TABLE),
+            BadOrigin
+        );
+
+        // Ensure the correct error if the table is not open
+        assert_noop!(
+            CoinFlipModule::play(RuntimeOrigin::signed(2), TABLE),
+            Error::<Test>::UnknownTable
+        );
+
+        // Check the balances in genesis config
+        assert_eq!(Balances::total_balance(&2), 20);
+
+        // a table with an entry fee higher than the balances
+        let expensive = open_table(30);
+        assert_noop!(
+            CoinFlipModule::play(RuntimeOrigin::signed(2), expensive),
+            TokenError::FundsUnavailable
+        );
+
+        // a table with an entry fee lower than the balances
+        let cheap = open_table(10);
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), cheap));
+    })
+}
+
+#[test]
+fn play_should_work_for_win() {
+    new_test_ext().execute_with(|| {
+        open_table(10);
+        seed_pot(TABLE, 30);
+        <Nonce<Test>>::put(0);
+        let issuance = Balances::total_issuance();
+
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), TABLE));
+        assert_eq!(CoinFlipModule::pot(TABLE), 10);
+        assert_eq!(Balances::total_balance(&2), 40); // 20 - 10 (payment) + 30 (reward)
+        assert_eq!(
+            Balances::total_balance(&CoinFlipModule::account_id(TABLE)),
+            10
+        );
+        assert_eq!(Balances::total_issuance(), issuance);
+        assert_eq!(CoinFlipModule::nonce(), 1);
+    })
+}
+
+#[test]
+fn play_should_work_for_lose() {
+    new_test_ext().execute_with(|| {
+        open_table(10);
+        seed_pot(TABLE, 30);
+        <Nonce<Test>>::put(1);
+        let issuance = Balances::total_issuance();
+
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), TABLE));
+        assert_eq!(CoinFlipModule::pot(TABLE), 40);
+        assert_eq!(Balances::total_balance(&2), 10); // 20 - 10 (payment)
+        assert_eq!(
+            Balances::total_balance(&CoinFlipModule::account_id(TABLE)),
+            40
+        );
+        assert_eq!(Balances::total_issuance(), issuance);
+        assert_eq!(CoinFlipModule::nonce(), 2);
+    })
+}
+
+#[test]
+fn total_issuance_is_unchanged_across_plays() {
+    new_test_ext().execute_with(|| {
+        open_table(10);
+        let issuance = Balances::total_issuance();
+
+        // win, then lose
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), TABLE));
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), TABLE));
+
+        assert_eq!(Balances::total_issuance(), issuance);
+        assert_eq!(
+            Balances::total_balance(&1) + Balances::total_balance(&2) + CoinFlipModule::pot(TABLE),
+            issuance
+        );
+    })
+}
+
+#[test]
+fn pot_is_reconciled_with_the_pot_account() {
+    new_test_ext().execute_with(|| {
+        open_table(10);
+        seed_pot(TABLE, 30);
+        <Nonce<Test>>::put(1);
+
+        // a donation sent straight to the pot account joins the jackpot
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(1),
+            CoinFlipModule::account_id(TABLE),
+            5
+        ));
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), TABLE));
+
+        assert_eq!(CoinFlipModule::pot(TABLE), 45);
+        assert_eq!(
+            CoinFlipModule::pot(TABLE),
+            Balances::total_balance(&CoinFlipModule::account_id(TABLE))
+        );
+    })
+}
+
+#[test]
+fn house_cut_goes_to_the_owner() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CoinFlipModule::create_table(
+            RuntimeOrigin::signed(1),
+            10,
+            1000,
+            Permill::from_percent(20)
+        ));
+        seed_pot(TABLE, 30);
+        <Nonce<Test>>::put(1);
+        let owner_balance = Balances::total_balance(&1);
+
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), TABLE));
+        assert_eq!(CoinFlipModule::pot(TABLE), 38);
+        assert_eq!(Balances::total_balance(&1), owner_balance + 2);
+        assert_eq!(Balances::total_balance(&2), 10);
+    })
+}
+
+#[test]
+fn payments_beyond_the_max_pot_go_to_the_owner() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CoinFlipModule::create_table(
+            RuntimeOrigin::signed(1),
+            10,
+            35,
+            Permill::zero()
+        ));
+        seed_pot(TABLE, 30);
+        <Nonce<Test>>::put(1);
+        let owner_balance = Balances::total_balance(&1);
+
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), TABLE));
+        assert_eq!(CoinFlipModule::pot(TABLE), 35);
+        assert_eq!(Balances::total_balance(&1), owner_balance + 5);
+    })
+}
+
+#[test]
+fn tables_are_independent() {
+    new_test_ext().execute_with(|| {
+        let first = open_table(10);
+        let second = open_table(5);
+        seed_pot(first, 30);
+        <Nonce<Test>>::put(0);
+
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), second));
+        // the win paid out the pot of the second table only
+        assert_eq!(Balances::total_balance(&2), 20); // 20 - 5 (payment) + 5 (reward)
+        assert_eq!(CoinFlipModule::pot(second), 5);
+        assert_eq!(CoinFlipModule::pot(first), 30);
+        assert_eq!(
+            Balances::total_balance(&CoinFlipModule::account_id(first)),
+            30
+        );
+    })
+}
+
+#[test]
+fn close_table_should_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        open_table(10);
+        Balances::set_balance(&2, 30);
+        <Nonce<Test>>::put(1);
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), TABLE));
+        let owner_balance = Balances::total_balance(&1);
+
+        assert_noop!(
+            CoinFlipModule::close_table(RuntimeOrigin::signed(2), TABLE),
+            Error::<Test>::NotTableOwner
+        );
+        assert_noop!(
+            CoinFlipModule::close_table(RuntimeOrigin::signed(1), 1),
+            Error::<Test>::UnknownTable
+        );
+        // the pot holds the payment player 2 lost, which is owed to the next winner
+        assert_noop!(
+            CoinFlipModule::close_table(RuntimeOrigin::signed(1), TABLE),
+            Error::<Test>::PotOwedToPlayers
+        );
+
+        <Nonce<Test>>::put(0);
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), TABLE));
+        assert_eq!(Balances::total_balance(&2), 30); // 30 - 10 - 10 + 20 (reward)
+        assert_ok!(CoinFlipModule::close_table(RuntimeOrigin::signed(1), TABLE));
+        System::assert_last_event(Event::<Test>::TableClosed(TABLE, 10).into());
+        assert_eq!(Balances::total_balance(&1), owner_balance + 10);
+        assert_eq!(
+            Balances::total_balance(&CoinFlipModule::account_id(TABLE)),
+            0
+        );
+        assert_eq!(CoinFlipModule::tables(TABLE), None);
+        assert_noop!(
+            CoinFlipModule::play(RuntimeOrigin::signed(2), TABLE),
+            Error::<Test>::UnknownTable
+        );
+    })
+}
+
+const HOUSE_SEED: H256 = H256::repeat_byte(7);
+const SALT: H256 = H256::repeat_byte(42);
+
+// Start a commit-reveal round: open a table, fund the pot and commit the house seed.
+fn start_round() {
+    open_table(10);
+    seed_pot(TABLE, 30);
+    System::set_block_number(1);
+    assert_ok!(CoinFlipModule::commit_house_seed(
+        RuntimeOrigin::root(),
+        BlakeTwo256::hash_of(&HOUSE_SEED)
+    ));
+}
+
+// Close the house commitment and reveal its seed in the next block.
+fn reveal_house() {
+    assert_ok!(CoinFlipModule::close_house_commitment(RuntimeOrigin::root()));
+    System::set_block_number(System::block_number() + 1);
+    assert_ok!(CoinFlipModule::reveal_house_seed(
+        RuntimeOrigin::root(),
+        HOUSE_SEED
+    ));
+}
+
+fn commit(player: u64, choice: bool) {
+    assert_ok!(CoinFlipModule::commit(
+        RuntimeOrigin::signed(player),
+        TABLE,
+        BlakeTwo256::hash_of(&(player, TABLE, choice, SALT))
+    ));
+}
+
+#[test]
+fn commit_reveal_should_work_for_win() {
+    new_test_ext().execute_with(|| {
+        start_round();
+        let heads = CoinFlipModule::is_heads(&HOUSE_SEED, &SALT);
+        commit(2, heads);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::Stake.into(), &2), 10);
+
+        reveal_house();
+        System::set_block_number(2);
+        let issuance = Balances::total_issuance();
+        assert_ok!(CoinFlipModule::reveal(
+            RuntimeOrigin::signed(2),
+            TABLE,
+            heads,
+            SALT
+        ));
+
+        assert_eq!(CoinFlipModule::pot(TABLE), 10);
+        assert_eq!(Balances::total_balance(&2), 40); // 20 - 10 (stake) + 30 (reward)
+        assert_eq!(Balances::balance_on_hold(&HoldReason::Stake.into(), &2), 0);
+        assert_eq!(Balances::total_issuance(), issuance);
+        assert_eq!(CoinFlipModule::commitments(TABLE, 2), None);
+    })
+}
+
+#[test]
+fn commit_reveal_should_work_for_lose() {
+    new_test_ext().execute_with(|| {
+        start_round();
+        let heads = CoinFlipModule::is_heads(&HOUSE_SEED, &SALT);
+        commit(2, !heads);
+        reveal_house();
+        System::set_block_number(2);
+
+        assert_ok!(CoinFlipModule::reveal(
+            RuntimeOrigin::signed(2),
+            TABLE,
+            !heads,
+            SALT
+        ));
+        assert_eq!(CoinFlipModule::pot(TABLE), 40);
+        assert_eq!(Balances::total_balance(&2), 10); // 20 - 10 (stake)
+    })
+}
+
+#[test]
+fn reveal_security_check_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CoinFlipModule::commit(RuntimeOrigin::signed(2), TABLE, SALT),
+            Error::<Test>::UnknownTable
+        );
+        assert_noop!(
+            CoinFlipModule::commit_house_seed(RuntimeOrigin::signed(1), SALT),
+            BadOrigin
+        );
+
+        start_round();
+        assert_noop!(
+            CoinFlipModule::commit_house_seed(RuntimeOrigin::root(), SALT),
+            Error::<Test>::HouseCommitmentPending
+        );
+        commit(2, true);
+        assert_noop!(
+            CoinFlipModule::commit(RuntimeOrigin::signed(2), TABLE, SALT),
+            Error::<Test>::AlreadyCommitted
+        );
+
+        assert_noop!(
+            CoinFlipModule::reveal(RuntimeOrigin::signed(2), TABLE, true, SALT),
+            Error::<Test>::TooEarly
+        );
+        System::set_block_number(2);
+        assert_noop!(
+            CoinFlipModule::reveal(RuntimeOrigin::signed(2), TABLE, true, SALT),
+            Error::<Test>::HouseSeedNotRevealed
+        );
+        assert_noop!(
+            CoinFlipModule::reveal_house_seed(RuntimeOrigin::root(), HOUSE_SEED),
+            Error::<Test>::HouseCommitmentOpen
+        );
+        assert_noop!(
+            CoinFlipModule::close_house_commitment(RuntimeOrigin::signed(1)),
+            BadOrigin
+        );
+        assert_ok!(CoinFlipModule::close_house_commitment(RuntimeOrigin::root()));
+        assert_noop!(
+            CoinFlipModule::close_house_commitment(RuntimeOrigin::root()),
+            Error::<Test>::NoHouseCommitment
+        );
+        assert_noop!(
+            CoinFlipModule::commit_house_seed(RuntimeOrigin::root(), SALT),
+            Error::<Test>::HouseCommitmentPending
+        );
+        System::set_block_number(3);
+        assert_noop!(
+            CoinFlipModule::reveal_house_seed(RuntimeOrigin::root(), SALT),
+            Error::<Test>::InvalidReveal
+        );
+        assert_ok!(CoinFlipModule::reveal_house_seed(
+            RuntimeOrigin::root(),
+            HOUSE_SEED
+        ));
+        assert_noop!(
+            CoinFlipModule::reveal(RuntimeOrigin::signed(2), TABLE, false, SALT),
+            Error::<Test>::InvalidReveal
+        );
+
+        // the house seed is known, so nobody can commit against it anymore
+        assert_noop!(
+            CoinFlipModule::commit(RuntimeOrigin::signed(1), TABLE, SALT),
+            Error::<Test>::NoHouseCommitment
+        );
+
+        System::set_block_number(8);
+        assert_noop!(
+            CoinFlipModule::reveal(RuntimeOrigin::signed(2), TABLE, true, SALT),
+            Error::<Test>::TooLate
+        );
+    })
+}
+
+#[test]
+fn commitments_close_a_block_before_the_house_reveals() {
+    new_test_ext().execute_with(|| {
+        start_round();
+        assert_ok!(CoinFlipModule::close_house_commitment(RuntimeOrigin::root()));
+        System::assert_last_event(
+            Event::<Test>::HouseClosed(BlakeTwo256::hash_of(&HOUSE_SEED)).into(),
+        );
+
+        // a player who sees the reveal coming cannot commit in the same block
+        assert_noop!(
+            CoinFlipModule::commit(RuntimeOrigin::signed(2), TABLE, SALT),
+            Error::<Test>::NoHouseCommitment
+        );
+        assert_noop!(
+            CoinFlipModule::reveal_house_seed(RuntimeOrigin::root(), HOUSE_SEED),
+            Error::<Test>::TooEarly
+        );
+
+        System::set_block_number(2);
+        assert_ok!(CoinFlipModule::reveal_house_seed(
+            RuntimeOrigin::root(),
+            HOUSE_SEED
+        ));
+        assert_noop!(
+            CoinFlipModule::commit(RuntimeOrigin::signed(2), TABLE, SALT),
+            Error::<Test>::NoHouseCommitment
+        );
+    })
+}
+
+#[test]
+fn house_seeds_are_pruned_once_no_commitment_refers_to_them() {
+    new_test_ext().execute_with(|| {
+        let house_commitment = BlakeTwo256::hash_of(&HOUSE_SEED);
+        start_round();
+        let heads = CoinFlipModule::is_heads(&HOUSE_SEED, &SALT);
+        commit(1, heads);
+        commit(2, heads);
+        assert_eq!(CoinFlipModule::house_commitment_refs(house_commitment), 2);
+        reveal_house();
+
+        assert_ok!(CoinFlipModule::reveal(
+            RuntimeOrigin::signed(2),
+            TABLE,
+            heads,
+            SALT
+        ));
+        assert_eq!(CoinFlipModule::house_commitment_refs(house_commitment), 1);
+        assert_eq!(
+            CoinFlipModule::house_seeds(house_commitment),
+            Some((HOUSE_SEED, 2))
+        );
+
+        System::set_block_number(8);
+        assert_ok!(CoinFlipModule::forfeit(RuntimeOrigin::signed(2), TABLE, 1));
+        assert_eq!(CoinFlipModule::house_commitment_refs(house_commitment), 0);
+        assert_eq!(CoinFlipModule::house_seeds(house_commitment), None);
+
+        // a seed nobody played against is not stored at all
+        assert_ok!(CoinFlipModule::commit_house_seed(
+            RuntimeOrigin::root(),
+            house_commitment
+        ));
+        reveal_house();
+        assert_eq!(CoinFlipModule::house_seeds(house_commitment), None);
+    })
+}
+
+#[test]
+fn forfeit_should_move_the_stake_to_the_pot() {
+    new_test_ext().execute_with(|| {
+        start_round();
+        commit(2, true);
+        reveal_house();
+
+        System::set_block_number(7);
+        assert_noop!(
+            CoinFlipModule::forfeit(RuntimeOrigin::signed(1), TABLE, 2),
+            Error::<Test>::RevealWindowOpen
+        );
+
+        System::set_block_number(8);
+        let issuance = Balances::total_issuance();
+        assert_ok!(CoinFlipModule::forfeit(RuntimeOrigin::signed(1), TABLE, 2));
+        assert_eq!(CoinFlipModule::pot(TABLE), 40);
+        assert_eq!(Balances::total_balance(&2), 10);
+        assert_eq!(Balances::total_issuance(), issuance);
+        assert_noop!(
+            CoinFlipModule::forfeit(RuntimeOrigin::signed(1), TABLE, 2),
+            Error::<Test>::NoCommitment
+        );
+    })
+}
+
+#[test]
+fn forfeit_should_refund_when_the_house_did_not_reveal() {
+    new_test_ext().execute_with(|| {
+        start_round();
+        commit(2, true);
+
+        System::set_block_number(8);
+        assert_ok!(CoinFlipModule::forfeit(RuntimeOrigin::signed(1), TABLE, 2));
+        assert_eq!(CoinFlipModule::pot(TABLE), 30);
+        assert_eq!(Balances::balance(&2), 20);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::Stake.into(), &2), 0);
+    })
+}
+
+#[test]
+fn forfeit_should_refund_when_the_house_revealed_too_late() {
+    new_test_ext().execute_with(|| {
+        start_round();
+        commit(2, true);
+
+        // the reveal window of the player closed after block 7
+        System::set_block_number(8);
+        reveal_house();
+        assert_eq!(
+            CoinFlipModule::house_seeds(BlakeTwo256::hash_of(&HOUSE_SEED)),
+            Some((HOUSE_SEED, 9))
+        );
+
+        assert_ok!(CoinFlipModule::forfeit(RuntimeOrigin::signed(1), TABLE, 2));
+        System::assert_last_event(Event::<Test>::Refunded(TABLE, 2, 10).into());
+        assert_eq!(CoinFlipModule::pot(TABLE), 30);
+        assert_eq!(Balances::balance(&2), 20);
+        assert_eq!(CoinFlipModule::stats(2), PlayerStats::default());
+    })
+}
+
+#[test]
+fn copied_commitments_cannot_be_revealed() {
+    new_test_ext().execute_with(|| {
+        start_round();
+        let heads = CoinFlipModule::is_heads(&HOUSE_SEED, &SALT);
+        commit(2, heads);
+        // player 1 copies the commitment of player 2 without knowing the choice
+        let copied = CoinFlipModule::commitments(TABLE, 2).unwrap().hash;
+        assert_ok!(CoinFlipModule::commit(
+            RuntimeOrigin::signed(1),
+            TABLE,
+            copied
+        ));
+        reveal_house();
+
+        // once player 2 revealed, the choice is public but does not match for player 1
+        System::set_block_number(2);
+        assert_ok!(CoinFlipModule::reveal(
+            RuntimeOrigin::signed(2),
+            TABLE,
+            heads,
+            SALT
+        ));
+        assert_noop!(
+            CoinFlipModule::reveal(RuntimeOrigin::signed(1), TABLE, heads, SALT),
+            Error::<Test>::InvalidReveal
+        );
+    })
+}
+
+#[test]
+fn close_table_waits_for_pending_commitments() {
+    new_test_ext().execute_with(|| {
+        start_round();
+        commit(2, true);
+        assert_eq!(
+            CoinFlipModule::tables(TABLE).map(|table| table.commitments),
+            Some(1)
+        );
+
+        assert_noop!(
+            CoinFlipModule::close_table(RuntimeOrigin::signed(1), TABLE),
+            Error::<Test>::PendingCommitments
+        );
+
+        System::set_block_number(8);
+        assert_ok!(CoinFlipModule::forfeit(RuntimeOrigin::signed(1), TABLE, 2));
+        assert_eq!(
+            CoinFlipModule::tables(TABLE).map(|table| table.commitments),
+            Some(0)
+        );
+        // as if someone had won the jackpot
+        seed_pot(TABLE, 10);
+        assert_ok!(CoinFlipModule::close_table(RuntimeOrigin::signed(1), TABLE));
+    })
+}
+
+#[test]
+fn stats_are_recorded_for_every_game() {
+    new_test_ext().execute_with(|| {
+        open_table(10);
+        seed_pot(TABLE, 30);
+
+        // win, then lose
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), TABLE));
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), TABLE));
+
+        assert_eq!(
+            CoinFlipModule::stats(2),
+            PlayerStats {
+                games_played: 2,
+                wins: 1,
+                total_staked: 20,
+                total_won: 30,
+            }
+        );
+        assert_eq!(CoinFlipModule::stats(3), PlayerStats::default());
+    })
+}
+
+#[test]
+fn stats_are_kept_per_table() {
+    new_test_ext().execute_with(|| {
+        let first = open_table(10);
+        let second = open_table(5);
+        seed_pot(first, 30);
+
+        // win at the first table, then lose at the second
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), first));
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), second));
+
+        assert_eq!(
+            CoinFlipModule::table_stats(first, 2),
+            PlayerStats {
+                games_played: 1,
+                wins: 1,
+                total_staked: 10,
+                total_won: 30,
+            }
+        );
+        assert_eq!(
+            CoinFlipModule::table_stats(second, 2),
+            PlayerStats {
+                games_played: 1,
+                wins: 0,
+                total_staked: 5,
+                total_won: 0,
+            }
+        );
+        assert_eq!(CoinFlipModule::stats(2).games_played, 2);
+
+        // the stats outlive the table
+        assert_ok!(CoinFlipModule::close_table(RuntimeOrigin::signed(1), first));
+        assert_eq!(CoinFlipModule::table_stats(first, 2).wins, 1);
+    })
+}
+
+#[test]
+fn forfeited_bets_count_as_lost_games() {
+    new_test_ext().execute_with(|| {
+        start_round();
+        commit(2, true);
+        reveal_house();
+
+        System::set_block_number(8);
+        assert_ok!(CoinFlipModule::forfeit(RuntimeOrigin::signed(1), TABLE, 2));
+        assert_eq!(
+            CoinFlipModule::stats(2),
+            PlayerStats {
+                games_played: 1,
+                wins: 0,
+                total_staked: 10,
+                total_won: 0,
+            }
+        );
+        assert!(CoinFlipModule::leaderboard().is_empty());
+    })
+}
+
+#[test]
+fn leaderboard_ranks_the_biggest_winners() {
+    new_test_ext().execute_with(|| {
+        open_table(10);
+        Balances::set_balance(&3, 20);
+        Balances::set_balance(&4, 20);
+        let win = |player: u64, pot: u64| {
+            seed_pot(TABLE, pot);
+            <Nonce<Test>>::put(0);
+            assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(player), TABLE));
+        };
+
+        win(2, 30);
+        win(3, 50);
+        assert_eq!(
+            CoinFlipModule::leaderboard().into_inner(),
+            vec![(3, 50), (2, 30)]
+        );
+
+        // the board holds two players, so a smaller win does not make it
+        win(4, 20);
+        assert_eq!(
+            CoinFlipModule::leaderboard().into_inner(),
+            vec![(3, 50), (2, 30)]
+        );
+
+        // but the total of several wins does
+        win(4, 40);
+        assert_eq!(
+            CoinFlipModule::leaderboard().into_inner(),
+            vec![(4, 60), (3, 50)]
+        );
+
+        // players on the board move up
+        win(3, 20);
+        assert_eq!(
+            CoinFlipModule::leaderboard().into_inner(),
+            vec![(3, 70), (4, 60)]
+        );
+
+        // losing does not change the board
+        <Nonce<Test>>::put(1);
+        assert_ok!(CoinFlipModule::play(RuntimeOrigin::signed(2), TABLE));
+        assert_eq!(
+            CoinFlipModule::leaderboard().into_inner(),
+            vec![(3, 70), (4, 60)]
+        );
+    })
+}
@@ -0,0 +1,137 @@
+//! Placeholder weights for pallet_coinflip.
+//!
+//! These numbers are not benchmark results. They are hand estimates from the storage each call
+//! accesses, with the proof size of the leaderboard derived from its bound, and should be
+//! replaced once the benchmarks in `benchmarking.rs` have been run on reference hardware.
+
+use codec::MaxEncodedLen;
+use core::marker::PhantomData;
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+    BoundedVec,
+};
+
+/// Weight functions needed for pallet_coinflip.
+pub trait WeightInfo {
+    fn create_table() -> Weight;
+    fn play() -> Weight;
+    fn commit_house_seed() -> Weight;
+    fn reveal_house_seed() -> Weight;
+    fn commit() -> Weight;
+    fn reveal() -> Weight;
+    fn forfeit() -> Weight;
+    fn close_table() -> Weight;
+    fn close_house_commitment() -> Weight;
+}
+
+/// Placeholder weights for the runtime.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: crate::Config> SubstrateWeight<T> {
+    /// The largest proof of the leaderboard, which grows with `MaxLeaderboardLength`.
+    fn leaderboard() -> u64 {
+        type Board<T> = BoundedVec<
+            (<T as frame_system::Config>::AccountId, crate::BalanceOf<T>),
+            <T as crate::Config>::MaxLeaderboardLength,
+        >;
+        Board::<T>::max_encoded_len() as u64
+    }
+}
+impl<T: crate::Config> WeightInfo for SubstrateWeight<T> {
+    fn create_table() -> Weight {
+        Weight::from_parts(45_000_000, 6_196)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    fn play() -> Weight {
+        Weight::from_parts(70_000_000, 13_379)
+            .saturating_add(Weight::from_parts(0, Self::leaderboard()))
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+    fn commit_house_seed() -> Weight {
+        Weight::from_parts(15_000_000, 1_517)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn reveal_house_seed() -> Weight {
+        Weight::from_parts(20_000_000, 3_082)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn commit() -> Weight {
+        Weight::from_parts(45_000_000, 8_551)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    fn reveal() -> Weight {
+        Weight::from_parts(85_000_000, 18_413)
+            .saturating_add(Weight::from_parts(0, Self::leaderboard()))
+            .saturating_add(T::DbWeight::get().reads(11_u64))
+            .saturating_add(T::DbWeight::get().writes(12_u64))
+    }
+    fn forfeit() -> Weight {
+        Weight::from_parts(70_000_000, 16_180)
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
+    }
+    fn close_table() -> Weight {
+        Weight::from_parts(40_000_000, 6_196)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    fn close_house_commitment() -> Weight {
+        Weight::from_parts(15_000_000, 1_517)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+}
+
+/// The same placeholders with `RocksDbWeight`, for tests.
+impl WeightInfo for () {
+    fn create_table() -> Weight {
+        Weight::from_parts(45_000_000, 6_196)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn play() -> Weight {
+        Weight::from_parts(70_000_000, 13_379)
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+    fn commit_house_seed() -> Weight {
+        Weight::from_parts(15_000_000, 1_517)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn reveal_house_seed() -> Weight {
+        Weight::from_parts(20_000_000, 3_082)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn commit() -> Weight {
+        Weight::from_parts(45_000_000, 8_551)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn reveal() -> Weight {
+        Weight::from_parts(85_000_000, 18_413)
+            .saturating_add(RocksDbWeight::get().reads(11_u64))
+            .saturating_add(RocksDbWeight::get().writes(12_u64))
+    }
+    fn forfeit() -> Weight {
+        Weight::from_parts(70_000_000, 16_180)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+    }
+    fn close_table() -> Weight {
+        Weight::from_parts(40_000_000, 6_196)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    fn close_house_commitment() -> Weight {
+        Weight::from_parts(15_000_000, 1_517)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+}
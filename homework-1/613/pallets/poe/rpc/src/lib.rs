@@ -0,0 +1,325 @@
+//! RPC interface for the Proof of Existance pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::{Codec, Encode};
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+    offchain::{OffchainStorage, STORAGE_PREFIX},
+    Bytes,
+};
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_poe_runtime_api::{ClaimDetails, PoeApi as PoeRuntimeApi, ProvenanceRecord};
+
+/// The largest page `poe_claimsOf` and `poe_hashClaimsOf` will return, whatever the requested
+/// limit.
+pub const MAX_CLAIMS_PER_PAGE: u32 = 100;
+
+/// A page of claims owned by an account.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimsPage {
+    /// The claims in this page.
+    pub claims: Vec<Bytes>,
+    /// The cursor to pass as `start_after` to fetch the next page.
+    pub next: Option<Bytes>,
+}
+
+/// A page of hash-only claims owned by an account.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HashClaimsPage<Hash> {
+    /// The claim hashes in this page.
+    pub hashes: Vec<Hash>,
+    /// The cursor to pass as `start_after` to fetch the next page.
+    pub next: Option<Hash>,
+}
+
+/// Queries of the PoE registries of a runtime.
+///
+/// Each method takes the `registry` to look in, named after its pallet in the runtime metadata,
+/// e.g. `PoeLegal`.
+#[rpc(client, server)]
+pub trait PoeApi<BlockHash, AccountId, BlockNumber, Hash> {
+    /// Look up the owner and creation block of a claim.
+    #[method(name = "poe_getClaim")]
+    fn get_claim(
+        &self,
+        registry: String,
+        claim: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ClaimDetails<AccountId, BlockNumber>>>;
+
+    /// Look up the owner and creation block of a hash-only claim.
+    #[method(name = "poe_getHashClaim")]
+    fn get_hash_claim(
+        &self,
+        registry: String,
+        hash: Hash,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ClaimDetails<AccountId, BlockNumber>>>;
+
+    /// The ownership history of a claim, oldest first.
+    #[method(name = "poe_claimHistory")]
+    fn claim_history(
+        &self,
+        registry: String,
+        claim: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ProvenanceRecord<AccountId, BlockNumber>>>;
+
+    /// The ownership history of a hash-only claim, oldest first.
+    #[method(name = "poe_hashClaimHistory")]
+    fn hash_claim_history(
+        &self,
+        registry: String,
+        hash: Hash,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ProvenanceRecord<AccountId, BlockNumber>>>;
+
+    /// Check that a document hash is part of a registered Merkle claim.
+    ///
+    /// The tree must be built by hashing each document hash as `hash(0x00 ++ leaf)` and each
+    /// pair of nodes, the lower one first, as `hash(0x01 ++ lower ++ higher)`. `proof` lists the
+    /// sibling of each node from the leaf up.
+    #[method(name = "poe_verifyInclusion")]
+    fn verify_inclusion(
+        &self,
+        registry: String,
+        root: Hash,
+        leaf: Hash,
+        proof: Vec<Hash>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<bool>;
+
+    /// List the claims owned by an account, one page at a time.
+    #[method(name = "poe_claimsOf")]
+    fn claims_of(
+        &self,
+        registry: String,
+        owner: AccountId,
+        start_after: Option<Bytes>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<ClaimsPage>;
+
+    /// List the hash-only claims owned by an account, one page at a time.
+    #[method(name = "poe_hashClaimsOf")]
+    fn hash_claims_of(
+        &self,
+        registry: String,
+        owner: AccountId,
+        start_after: Option<Hash>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<HashClaimsPage<Hash>>;
+}
+
+#[rpc(client, server)]
+pub trait PoeContentApi<Hash> {
+    /// The content of a hash claim created with `create_claim_with_content`, if this node
+    /// indexed it.
+    #[method(name = "poe_getClaimContent")]
+    fn get_claim_content(&self, hash: Hash) -> RpcResult<Option<Bytes>>;
+}
+
+/// Provides RPC methods to query the Proof of Existance pallet.
+pub struct Poe<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Poe<C, Block> {
+    /// Creates a new instance of the Poe RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Serves claim content from the off-chain database of a node running with off-chain
+/// indexing enabled.
+pub struct PoeContent<S> {
+    storage: S,
+}
+
+impl<S> PoeContent<S> {
+    /// Creates a new instance of the claim content RPC helper.
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+}
+
+impl<S, Hash> PoeContentApiServer<Hash> for PoeContent<S>
+where
+    S: OffchainStorage + 'static,
+    Hash: Encode + DeserializeOwned + Send + Sync + 'static,
+{
+    fn get_claim_content(&self, hash: Hash) -> RpcResult<Option<Bytes>> {
+        Ok(self
+            .storage
+            .get(STORAGE_PREFIX, &pallet_poe::offchain_content_key(&hash))
+            .map(Into::into))
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+fn runtime_error(e: impl std::fmt::Display) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Unable to query claims.",
+        Some(e.to_string()),
+    )
+}
+
+impl<C, Block, AccountId, BlockNumber, Hash>
+    PoeApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber, Hash> for Poe<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: PoeRuntimeApi<Block, AccountId, BlockNumber, Hash>,
+    AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    BlockNumber: Codec + Serialize + Send + Sync + 'static,
+    Hash: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn get_claim(
+        &self,
+        registry: String,
+        claim: Bytes,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ClaimDetails<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_claim(at_hash, registry.into_bytes(), claim.to_vec())
+            .map_err(runtime_error)
+    }
+
+    fn get_hash_claim(
+        &self,
+        registry: String,
+        hash: Hash,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ClaimDetails<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_hash_claim(at_hash, registry.into_bytes(), hash)
+            .map_err(runtime_error)
+    }
+
+    fn claim_history(
+        &self,
+        registry: String,
+        claim: Bytes,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ProvenanceRecord<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.claim_history(at_hash, registry.into_bytes(), claim.to_vec())
+            .map_err(runtime_error)
+    }
+
+    fn hash_claim_history(
+        &self,
+        registry: String,
+        hash: Hash,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ProvenanceRecord<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.hash_claim_history(at_hash, registry.into_bytes(), hash)
+            .map_err(runtime_error)
+    }
+
+    fn verify_inclusion(
+        &self,
+        registry: String,
+        root: Hash,
+        leaf: Hash,
+        proof: Vec<Hash>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<bool> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.verify_inclusion(at_hash, registry.into_bytes(), root, leaf, proof)
+            .map_err(runtime_error)
+    }
+
+    fn claims_of(
+        &self,
+        registry: String,
+        owner: AccountId,
+        start_after: Option<Bytes>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<ClaimsPage> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let (claims, next) = api
+            .claims_of(
+                at_hash,
+                registry.into_bytes(),
+                owner,
+                start_after.map(|c| c.to_vec()),
+                limit.min(MAX_CLAIMS_PER_PAGE),
+            )
+            .map_err(runtime_error)?;
+
+        Ok(ClaimsPage {
+            claims: claims.into_iter().map(Into::into).collect(),
+            next: next.map(Into::into),
+        })
+    }
+
+    fn hash_claims_of(
+        &self,
+        registry: String,
+        owner: AccountId,
+        start_after: Option<Hash>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<HashClaimsPage<Hash>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let (hashes, next) = api
+            .hash_claims_of(
+                at_hash,
+                registry.into_bytes(),
+                owner,
+                start_after,
+                limit.min(MAX_CLAIMS_PER_PAGE),
+            )
+            .map_err(runtime_error)?;
+
+        Ok(HashClaimsPage { hashes, next })
+    }
+}
@@ -0,0 +1,115 @@
+//! Runtime API definition for the Proof of Existance pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+
+/// Descriptive information attached to a claim by its owner.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ClaimMetadata {
+    #[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+    pub content_type: Vec<u8>,
+    #[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+    pub uri: Vec<u8>,
+    #[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+    pub description: Vec<u8>,
+}
+
+/// The owner of a claim, when it was created and its metadata.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ClaimDetails<AccountId, BlockNumber> {
+    pub owner: AccountId,
+    pub block_number: BlockNumber,
+    /// Milliseconds since the Unix epoch at which the claim was created, if known.
+    pub timestamp: Option<u64>,
+    pub metadata: Option<ClaimMetadata>,
+}
+
+/// What happened to a claim in a provenance record.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum ClaimAction {
+    Created,
+    Transferred,
+    Revoked,
+    Expired,
+}
+
+/// A single entry in the ownership history of a claim.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ProvenanceRecord<AccountId, BlockNumber> {
+    pub owner: AccountId,
+    pub block_number: BlockNumber,
+    pub action: ClaimAction,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Queries of the Proof of Existance registry.
+    ///
+    /// A runtime may hold several registries. Each is named after its pallet in the runtime
+    /// metadata, e.g. `PoeLegal`; unknown registries hold no claims.
+    pub trait PoeApi<AccountId, BlockNumber, Hash>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+    {
+        /// Look up a claim by its raw bytes.
+        fn get_claim(
+            registry: Vec<u8>,
+            claim: Vec<u8>,
+        ) -> Option<ClaimDetails<AccountId, BlockNumber>>;
+
+        /// Look up a hash-only claim by its digest.
+        fn get_hash_claim(
+            registry: Vec<u8>,
+            hash: Hash,
+        ) -> Option<ClaimDetails<AccountId, BlockNumber>>;
+
+        /// The ownership history of a claim, oldest first.
+        fn claim_history(
+            registry: Vec<u8>,
+            claim: Vec<u8>,
+        ) -> Vec<ProvenanceRecord<AccountId, BlockNumber>>;
+
+        /// The ownership history of a hash-only claim, oldest first.
+        fn hash_claim_history(
+            registry: Vec<u8>,
+            hash: Hash,
+        ) -> Vec<ProvenanceRecord<AccountId, BlockNumber>>;
+
+        /// Whether `leaf` is part of the registered Merkle claim `root`.
+        ///
+        /// `proof` lists the sibling of each node from the leaf up. Leaves are hashed as
+        /// `hash(0x00 ++ leaf)` and pairs of nodes as `hash(0x01 ++ lower ++ higher)`.
+        fn verify_inclusion(registry: Vec<u8>, root: Hash, leaf: Hash, proof: Vec<Hash>) -> bool;
+
+        /// List at most `limit` claims owned by `owner`, starting after the `start_after` cursor.
+        ///
+        /// Returns the claims and the cursor of the next page, if any.
+        fn claims_of(
+            registry: Vec<u8>,
+            owner: AccountId,
+            start_after: Option<Vec<u8>>,
+            limit: u32,
+        ) -> (Vec<Vec<u8>>, Option<Vec<u8>>);
+
+        /// List at most `limit` hash-only claims owned by `owner`, like `claims_of`.
+        fn hash_claims_of(
+            registry: Vec<u8>,
+            owner: AccountId,
+            start_after: Option<Hash>,
+            limit: u32,
+        ) -> (Vec<Hash>, Option<Hash>);
+    }
+}
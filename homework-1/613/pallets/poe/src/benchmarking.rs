@@ -0,0 +1,601 @@
+//! Benchmarking setup for pallet-poe
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Poe;
+use alloc::vec;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn funded_account<T: Config<I>, I: 'static>(name: &'static str, index: u32) -> T::AccountId {
+    let who = account(name, index, SEED);
+    let deposit = Poe::<T, I>::claim_deposit(T::MaxClaimLenth::get());
+    let _ = T::Currency::set_balance(
+        &who,
+        T::Currency::minimum_balance().saturating_add(deposit.saturating_mul(100u32.into())),
+    );
+    who
+}
+
+fn max_claim<T: Config<I>, I: 'static>(seed: u8) -> BoundedVec<u8, T::MaxClaimLenth> {
+    vec![seed; T::MaxClaimLenth::get() as usize]
+        .try_into()
+        .unwrap()
+}
+
+/// A full-length claim that is distinct for each `index`.
+fn nth_claim<T: Config<I>, I: 'static>(index: u32) -> BoundedVec<u8, T::MaxClaimLenth> {
+    let mut claim = vec![0u8; T::MaxClaimLenth::get() as usize];
+    for (byte, value) in claim.iter_mut().zip(index.to_le_bytes()) {
+        *byte = value;
+    }
+    claim.try_into().unwrap()
+}
+
+fn batch<T: Config<I>, I: 'static>(
+    n: u32,
+) -> BoundedVec<BoundedVec<u8, T::MaxClaimLenth>, T::MaxBatchSize> {
+    (0..n)
+        .map(nth_claim::<T, I>)
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+fn max_metadata<T: Config<I>, I: 'static>() -> BoundedVec<u8, T::MaxMetadataLength> {
+    vec![0; T::MaxMetadataLength::get() as usize]
+        .try_into()
+        .unwrap()
+}
+
+fn max_reason<T: Config<I>, I: 'static>() -> BoundedVec<u8, T::MaxReasonLength> {
+    vec![0; T::MaxReasonLength::get() as usize]
+        .try_into()
+        .unwrap()
+}
+
+/// Mint claim NFTs into a collection, if the runtime can provide one, so that their cost is
+/// accounted for.
+fn set_up_nft_collection<T: Config<I>, I: 'static>() {
+    NftCollection::<T, I>::set(T::BenchmarkHelper::nft_collection());
+}
+
+/// Create a claim for `owner` with everything that has to be cleaned up when it is removed.
+fn create_full_claim<T: Config<I>, I: 'static>(
+    owner: &T::AccountId,
+    operator: &T::AccountId,
+) -> BoundedVec<u8, T::MaxClaimLenth> {
+    let claim = max_claim::<T, I>(0);
+    let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+    let origin: T::RuntimeOrigin = RawOrigin::Signed(owner.clone()).into();
+    Poe::<T, I>::create_claim(origin.clone(), claim.clone(), Some(expires_at)).unwrap();
+    Poe::<T, I>::set_claim_metadata(
+        origin.clone(),
+        claim.clone(),
+        max_metadata::<T, I>(),
+        max_metadata::<T, I>(),
+        max_metadata::<T, I>(),
+    )
+    .unwrap();
+    Poe::<T, I>::offer_claim(origin.clone(), claim.clone(), operator.clone(), None).unwrap();
+    Poe::<T, I>::approve_operator(origin, operator.clone(), Some(claim.clone())).unwrap();
+    claim
+}
+
+#[instance_benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_claim() {
+        set_up_nft_collection::<T, I>();
+        let caller = funded_account::<T, I>("caller", 0);
+        let claim = max_claim::<T, I>(0);
+        let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            claim.clone(),
+            Some(expires_at),
+        );
+
+        assert_eq!(
+            Proofs::<T, I>::get(&claim).map(|info| info.owner),
+            Some(caller)
+        );
+    }
+
+    #[benchmark]
+    fn revoke_claim() {
+        set_up_nft_collection::<T, I>();
+        let caller = funded_account::<T, I>("caller", 0);
+        let operator = funded_account::<T, I>("operator", 0);
+        let claim = create_full_claim::<T, I>(&caller, &operator);
+        // Revoke as the operator so the approval is checked too.
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), claim.clone());
+
+        assert!(!Proofs::<T, I>::contains_key(&claim));
+    }
+
+    #[benchmark]
+    fn transfer_claim() {
+        set_up_nft_collection::<T, I>();
+        let caller = funded_account::<T, I>("caller", 0);
+        let operator = funded_account::<T, I>("operator", 0);
+        let to = funded_account::<T, I>("to", 0);
+        let claim = create_full_claim::<T, I>(&caller, &operator);
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), claim.clone(), to.clone());
+
+        assert_eq!(Proofs::<T, I>::get(&claim).map(|info| info.owner), Some(to));
+    }
+
+    #[benchmark]
+    fn create_hash_claim() {
+        let caller = funded_account::<T, I>("caller", 0);
+        let hash = Poe::<T, I>::hash_of(b"document");
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), hash);
+
+        assert!(HashProofs::<T, I>::contains_key(hash));
+    }
+
+    #[benchmark]
+    fn revoke_hash_claim() {
+        let caller = funded_account::<T, I>("caller", 0);
+        let hash = Poe::<T, I>::hash_of(b"document");
+        let origin: T::RuntimeOrigin = RawOrigin::Signed(caller.clone()).into();
+        Poe::<T, I>::create_merkle_claim(origin.clone(), hash).unwrap();
+        Poe::<T, I>::set_hash_claim_metadata(
+            origin,
+            hash,
+            max_metadata::<T, I>(),
+            max_metadata::<T, I>(),
+            max_metadata::<T, I>(),
+        )
+        .unwrap();
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), hash);
+
+        assert!(!HashProofs::<T, I>::contains_key(hash));
+    }
+
+    #[benchmark]
+    fn transfer_hash_claim() {
+        let caller = funded_account::<T, I>("caller", 0);
+        let to = funded_account::<T, I>("to", 0);
+        let hash = Poe::<T, I>::hash_of(b"document");
+        Poe::<T, I>::create_hash_claim(RawOrigin::Signed(caller.clone()).into(), hash).unwrap();
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), hash, to.clone());
+
+        assert_eq!(
+            HashProofs::<T, I>::get(hash).map(|info| info.owner),
+            Some(to)
+        );
+    }
+
+    #[benchmark]
+    fn offer_claim() {
+        let caller = funded_account::<T, I>("caller", 0);
+        let to: T::AccountId = account("to", 0, SEED);
+        let claim = max_claim::<T, I>(0);
+        Poe::<T, I>::create_claim(
+            RawOrigin::Signed(caller.clone()).into(),
+            claim.clone(),
+            None,
+        )
+        .unwrap();
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            claim.clone(),
+            to,
+            Some(10u32.into()),
+        );
+
+        assert!(PendingTransfers::<T, I>::contains_key(&claim));
+    }
+
+    #[benchmark]
+    fn accept_claim() {
+        set_up_nft_collection::<T, I>();
+        let caller = funded_account::<T, I>("caller", 0);
+        let operator = funded_account::<T, I>("operator", 0);
+        let claim = create_full_claim::<T, I>(&caller, &operator);
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator.clone()), claim.clone());
+
+        assert_eq!(
+            Proofs::<T, I>::get(&claim).map(|info| info.owner),
+            Some(operator)
+        );
+    }
+
+    #[benchmark]
+    fn cancel_offer() {
+        let caller = funded_account::<T, I>("caller", 0);
+        let operator = funded_account::<T, I>("operator", 0);
+        let claim = create_full_claim::<T, I>(&caller, &operator);
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), claim.clone());
+
+        assert!(!PendingTransfers::<T, I>::contains_key(&claim));
+    }
+
+    #[benchmark]
+    fn set_claim_metadata() {
+        let caller = funded_account::<T, I>("caller", 0);
+        let claim = max_claim::<T, I>(0);
+        Poe::<T, I>::create_claim(
+            RawOrigin::Signed(caller.clone()).into(),
+            claim.clone(),
+            None,
+        )
+        .unwrap();
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            claim.clone(),
+            max_metadata::<T, I>(),
+            max_metadata::<T, I>(),
+            max_metadata::<T, I>(),
+        );
+
+        assert!(Metadata::<T, I>::contains_key(&claim));
+    }
+
+    #[benchmark]
+    fn set_hash_claim_metadata() {
+        let caller = funded_account::<T, I>("caller", 0);
+        let hash = Poe::<T, I>::hash_of(b"document");
+        Poe::<T, I>::create_hash_claim(RawOrigin::Signed(caller.clone()).into(), hash).unwrap();
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            hash,
+            max_metadata::<T, I>(),
+            max_metadata::<T, I>(),
+            max_metadata::<T, I>(),
+        );
+
+        assert!(HashMetadata::<T, I>::contains_key(hash));
+    }
+
+    #[benchmark]
+    fn create_merkle_claim() {
+        let caller = funded_account::<T, I>("caller", 0);
+        let root = Poe::<T, I>::hash_of(b"root");
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), root);
+
+        assert!(MerkleRoots::<T, I>::contains_key(root));
+    }
+
+    #[benchmark]
+    fn verify_inclusion(p: Linear<0, { T::MaxMerkleProofLength::get() }>) {
+        let caller = funded_account::<T, I>("caller", 0);
+        let leaf = Poe::<T, I>::hash_of(b"leaf");
+        let proof: Vec<T::Hash> = (0..p)
+            .map(|i| Poe::<T, I>::hash_of(&i.to_le_bytes()))
+            .collect();
+        let root = Poe::<T, I>::merkle_root(leaf, &proof);
+        Poe::<T, I>::create_merkle_claim(RawOrigin::Signed(caller.clone()).into(), root).unwrap();
+        let proof: BoundedVec<T::Hash, T::MaxMerkleProofLength> = proof.try_into().unwrap();
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), root, leaf, proof);
+    }
+
+    #[benchmark]
+    fn create_claim_signed() {
+        set_up_nft_collection::<T, I>();
+        let relayer = funded_account::<T, I>("relayer", 0);
+        let (signer, owner) = T::BenchmarkHelper::signer();
+        let claim = max_claim::<T, I>(0);
+        let deadline = frame_system::Pallet::<T>::block_number();
+        let payload = Poe::<T, I>::signed_claim_payload(claim.clone(), owner.clone(), deadline);
+        let signature = T::BenchmarkHelper::sign(&signer, &payload.encode());
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(relayer),
+            claim.clone(),
+            owner.clone(),
+            signature,
+            deadline,
+        );
+
+        assert_eq!(
+            Proofs::<T, I>::get(&claim).map(|info| info.owner),
+            Some(owner)
+        );
+    }
+
+    #[benchmark]
+    fn approve_operator() {
+        let caller = funded_account::<T, I>("caller", 0);
+        let operator: T::AccountId = account("operator", 0, SEED);
+        let claim = max_claim::<T, I>(0);
+        Poe::<T, I>::create_claim(
+            RawOrigin::Signed(caller.clone()).into(),
+            claim.clone(),
+            None,
+        )
+        .unwrap();
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            operator.clone(),
+            Some(claim.clone()),
+        );
+
+        assert_eq!(ClaimOperators::<T, I>::get(&claim), Some(operator));
+    }
+
+    #[benchmark]
+    fn revoke_operator() {
+        let caller = funded_account::<T, I>("caller", 0);
+        let operator = funded_account::<T, I>("operator", 0);
+        let claim = create_full_claim::<T, I>(&caller, &operator);
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), operator, Some(claim.clone()));
+
+        assert!(!ClaimOperators::<T, I>::contains_key(&claim));
+    }
+
+    #[benchmark]
+    fn freeze_claim() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller = funded_account::<T, I>("caller", 0);
+        let claim = max_claim::<T, I>(0);
+        Poe::<T, I>::create_claim(RawOrigin::Signed(caller).into(), claim.clone(), None)?;
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            claim.clone(),
+            max_reason::<T, I>(),
+        );
+
+        assert!(Frozen::<T, I>::contains_key(&claim));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unfreeze_claim() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller = funded_account::<T, I>("caller", 0);
+        let claim = max_claim::<T, I>(0);
+        Poe::<T, I>::create_claim(RawOrigin::Signed(caller).into(), claim.clone(), None)?;
+        Poe::<T, I>::freeze_claim(origin.clone(), claim.clone(), max_reason::<T, I>())?;
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            claim.clone(),
+            max_reason::<T, I>(),
+        );
+
+        assert!(!Frozen::<T, I>::contains_key(&claim));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_transfer_claim() -> Result<(), BenchmarkError> {
+        set_up_nft_collection::<T, I>();
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller = funded_account::<T, I>("caller", 0);
+        let operator = funded_account::<T, I>("operator", 0);
+        let to = funded_account::<T, I>("to", 0);
+        let claim = create_full_claim::<T, I>(&caller, &operator);
+        Poe::<T, I>::freeze_claim(origin.clone(), claim.clone(), max_reason::<T, I>())?;
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            claim.clone(),
+            to.clone(),
+            max_reason::<T, I>(),
+        );
+
+        assert_eq!(Proofs::<T, I>::get(&claim).map(|info| info.owner), Some(to));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_revoke_claim() -> Result<(), BenchmarkError> {
+        set_up_nft_collection::<T, I>();
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller = funded_account::<T, I>("caller", 0);
+        let operator = funded_account::<T, I>("operator", 0);
+        let claim = create_full_claim::<T, I>(&caller, &operator);
+        Poe::<T, I>::freeze_claim(origin.clone(), claim.clone(), max_reason::<T, I>())?;
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            claim.clone(),
+            max_reason::<T, I>(),
+        );
+
+        assert!(!Proofs::<T, I>::contains_key(&claim));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn on_initialize(n: Linear<0, { T::MaxExpiriesPerBlock::get() }>) {
+        set_up_nft_collection::<T, I>();
+        let caller = funded_account::<T, I>("caller", 0);
+        let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        for i in 0..n {
+            let claim = nth_claim::<T, I>(i);
+            let origin: T::RuntimeOrigin = RawOrigin::Signed(caller.clone()).into();
+            Poe::<T, I>::create_claim(origin.clone(), claim.clone(), Some(expires_at)).unwrap();
+            Poe::<T, I>::set_claim_metadata(
+                origin,
+                claim,
+                max_metadata::<T, I>(),
+                max_metadata::<T, I>(),
+                max_metadata::<T, I>(),
+            )
+            .unwrap();
+        }
+        #[block]
+        {
+            Poe::<T, I>::on_initialize(expires_at);
+        }
+
+        assert_eq!(ClaimCount::<T, I>::get(&caller), 0);
+    }
+
+    #[benchmark]
+    fn freeze_hash_claim() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller = funded_account::<T, I>("caller", 0);
+        let hash = Poe::<T, I>::hash_of(b"document");
+        Poe::<T, I>::create_hash_claim(RawOrigin::Signed(caller).into(), hash)?;
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, hash, max_reason::<T, I>());
+
+        assert!(HashFrozen::<T, I>::contains_key(hash));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unfreeze_hash_claim() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller = funded_account::<T, I>("caller", 0);
+        let hash = Poe::<T, I>::hash_of(b"document");
+        Poe::<T, I>::create_hash_claim(RawOrigin::Signed(caller).into(), hash)?;
+        Poe::<T, I>::freeze_hash_claim(origin.clone(), hash, max_reason::<T, I>())?;
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, hash, max_reason::<T, I>());
+
+        assert!(!HashFrozen::<T, I>::contains_key(hash));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_transfer_hash_claim() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller = funded_account::<T, I>("caller", 0);
+        let to = funded_account::<T, I>("to", 0);
+        let hash = Poe::<T, I>::hash_of(b"document");
+        Poe::<T, I>::create_hash_claim(RawOrigin::Signed(caller).into(), hash)?;
+        Poe::<T, I>::freeze_hash_claim(origin.clone(), hash, max_reason::<T, I>())?;
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            hash,
+            to.clone(),
+            max_reason::<T, I>(),
+        );
+
+        assert_eq!(
+            HashProofs::<T, I>::get(hash).map(|info| info.owner),
+            Some(to)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_revoke_hash_claim() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller = funded_account::<T, I>("caller", 0);
+        let hash = Poe::<T, I>::hash_of(b"document");
+        let signed: T::RuntimeOrigin = RawOrigin::Signed(caller).into();
+        Poe::<T, I>::create_merkle_claim(signed.clone(), hash)?;
+        Poe::<T, I>::set_hash_claim_metadata(
+            signed,
+            hash,
+            max_metadata::<T, I>(),
+            max_metadata::<T, I>(),
+            max_metadata::<T, I>(),
+        )?;
+        Poe::<T, I>::freeze_hash_claim(origin.clone(), hash, max_reason::<T, I>())?;
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, hash, max_reason::<T, I>());
+
+        assert!(!HashProofs::<T, I>::contains_key(hash));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_claims(n: Linear<1, { T::MaxBatchSize::get() }>) {
+        set_up_nft_collection::<T, I>();
+        let caller = funded_account::<T, I>("caller", 0);
+        let claims = batch::<T, I>(n);
+        // Best-effort mode, which pays for a storage layer per claim.
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), claims, false);
+
+        assert_eq!(ClaimCount::<T, I>::get(&caller), n);
+    }
+
+    #[benchmark]
+    fn revoke_claims(n: Linear<1, { T::MaxBatchSize::get() }>) {
+        set_up_nft_collection::<T, I>();
+        let caller = funded_account::<T, I>("caller", 0);
+        let claims = batch::<T, I>(n);
+        Poe::<T, I>::create_claims(
+            RawOrigin::Signed(caller.clone()).into(),
+            claims.clone(),
+            true,
+        )
+        .unwrap();
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), claims, false);
+
+        assert_eq!(ClaimCount::<T, I>::get(&caller), 0);
+    }
+
+    #[benchmark]
+    fn transfer_claims(n: Linear<1, { T::MaxBatchSize::get() }>) {
+        set_up_nft_collection::<T, I>();
+        let caller = funded_account::<T, I>("caller", 0);
+        let to = funded_account::<T, I>("to", 0);
+        let claims = batch::<T, I>(n);
+        Poe::<T, I>::create_claims(
+            RawOrigin::Signed(caller.clone()).into(),
+            claims.clone(),
+            true,
+        )
+        .unwrap();
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), claims, to.clone(), false);
+
+        assert_eq!(ClaimCount::<T, I>::get(&to), n);
+    }
+
+    #[benchmark]
+    fn create_claim_with_content(b: Linear<0, { T::MaxContentLength::get() }>) {
+        let caller = funded_account::<T, I>("caller", 0);
+        let content: BoundedVec<u8, T::MaxContentLength> =
+            vec![0u8; b as usize].try_into().unwrap();
+        let hash = Poe::<T, I>::hash_of(&content);
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), content);
+
+        assert!(HashProofs::<T, I>::contains_key(hash));
+    }
+
+    #[benchmark]
+    fn set_nft_collection() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, None);
+
+        assert_eq!(NftCollection::<T, I>::get(), None);
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Poe, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -1,102 +1,1793 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-use frame_support::pallet_prelude::*;
+extern crate alloc;
+
+use alloc::vec::Vec;
+use frame_support::{
+    pallet_prelude::*,
+    storage::with_storage_layer,
+    traits::{
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::{
+            nonfungibles_v2::{
+                Inspect as NftInspect, Mutate as NftMutate, Transfer as NftTransfer,
+            },
+            Fortitude, Precision, Restriction,
+        },
+        Incrementable, PalletInfoAccess, UnixTime,
+    },
+};
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
+use sp_runtime::{
+    traits::{Hash, IdentifyAccount, Saturating, Verify, Zero},
+    SaturatedConversion,
+};
 
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+/// Prefix of the off-chain database keys under which `create_claim_with_content` indexes
+/// claim content.
+pub const OFFCHAIN_CONTENT_PREFIX: &[u8] = b"poe::content";
+
+/// The off-chain database key of the content of the hash claim `hash`.
+pub fn offchain_content_key<Hash: Encode>(hash: &Hash) -> Vec<u8> {
+    (OFFCHAIN_CONTENT_PREFIX, hash).encode()
+}
+
+pub type BalanceOf<T, I = ()> =
+    <<T as Config<I>>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Descriptive information an owner can attach to a claim.
+#[derive(
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    RuntimeDebugNoBound,
+    Encode,
+    Decode,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxLength))]
+pub struct ClaimMetadata<MaxLength: Get<u32>> {
+    /// MIME type of the document, e.g. `application/pdf`.
+    pub content_type: BoundedVec<u8, MaxLength>,
+    /// Where the document can be retrieved off-chain, e.g. an IPFS CID.
+    pub uri: BoundedVec<u8, MaxLength>,
+    /// A short human readable description.
+    pub description: BoundedVec<u8, MaxLength>,
+}
+
+/// What happened to a claim in a provenance record.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ClaimAction {
+    Created,
+    Transferred,
+    Revoked,
+    Expired,
+}
+
+/// A single entry in the ownership history of a claim.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ProvenanceRecord<AccountId, BlockNumber> {
+    /// The owner of the claim after the action.
+    pub owner: AccountId,
+    pub block_number: BlockNumber,
+    pub action: ClaimAction,
+}
+
+/// The on-chain record of a claim.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ClaimInfo<AccountId, BlockNumber> {
+    pub owner: AccountId,
+    /// The block in which the claim was created.
+    pub block_number: BlockNumber,
+    /// Milliseconds since the Unix epoch at which the claim was created, or 0 for claims
+    /// created before timestamps were recorded.
+    pub timestamp: u64,
+}
+
+pub type ClaimInfoOf<T> = ClaimInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+pub type ProvenanceRecordOf<T> =
+    ProvenanceRecord<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+/// The byte prepended to a leaf of a Merkle claim before hashing it.
+pub const MERKLE_LEAF_PREFIX: u8 = 0;
+
+/// The byte prepended to a pair of nodes of a Merkle claim before hashing them.
+pub const MERKLE_NODE_PREFIX: u8 = 1;
+
+/// The message an owner signs to let someone else submit `create_claim_signed` for them.
+///
+/// The signature is over the SCALE encoding of this struct.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SignedClaimPayload<Claim, AccountId, BlockNumber, Hash> {
+    pub claim: Claim,
+    pub owner: AccountId,
+    /// The owner's current `SignerNonces` entry.
+    pub nonce: u32,
+    /// The last block in which the payload may be submitted.
+    pub deadline: BlockNumber,
+    /// The index of the pallet instance in the runtime, so that the signature cannot be
+    /// replayed on another instance.
+    pub pallet_index: u32,
+    /// The hash of the genesis block, so that the signature cannot be replayed on another
+    /// chain.
+    pub genesis_hash: Hash,
+}
+
+pub type SignedClaimPayloadOf<T, I> = SignedClaimPayload<
+    BoundedVec<u8, <T as Config<I>>::MaxClaimLenth>,
+    <T as frame_system::Config>::AccountId,
+    BlockNumberFor<T>,
+    <T as frame_system::Config>::Hash,
+>;
+
+/// Signs claim payloads and sets up claim NFTs in the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Public, AccountId, Signature, CollectionId> {
+    /// Create a new key pair, returning its public key and account.
+    fn signer() -> (Public, AccountId);
+    /// Sign `message` with the key of `signer`.
+    fn sign(signer: &Public, message: &[u8]) -> Signature;
+    /// Create a funded NFT collection to mint claim NFTs into.
+    ///
+    /// Without one, the benchmarks do not account for the NFTs mirroring claims.
+    fn nft_collection() -> Option<CollectionId> {
+        None
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<Public, AccountId, Signature, CollectionId>
+    BenchmarkHelper<Public, AccountId, Signature, CollectionId> for ()
+where
+    Public: Clone
+        + IdentifyAccount<AccountId = AccountId>
+        + From<sp_core::sr25519::Public>
+        + TryInto<sp_core::sr25519::Public>,
+    Signature: From<sp_core::sr25519::Signature>,
+{
+    fn signer() -> (Public, AccountId) {
+        let public: Public = sp_io::crypto::sr25519_generate(0.into(), None).into();
+        (public.clone(), public.into_account())
+    }
+
+    fn sign(signer: &Public, message: &[u8]) -> Signature {
+        let public = signer
+            .clone()
+            .try_into()
+            .ok()
+            .expect("signer was created from an sr25519 key; qed");
+        sp_io::crypto::sr25519_sign(0.into(), &public, message)
+            .expect("key was generated in the keystore; qed")
+            .into()
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+    pub trait Config<I: 'static = ()>: frame_system::Config {
+        type RuntimeEvent: From<Event<Self, I>>
+            + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason<I>>;
+        /// The currency used to hold claim deposits.
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
         #[pallet::constant]
         type MaxClaimLenth: Get<u32>;
+        /// The wall-clock time recorded with claims, usually `pallet_timestamp`.
+        type UnixTime: UnixTime;
+        /// The base deposit held for every claim.
+        #[pallet::constant]
+        type ClaimDepositBase: Get<BalanceOf<Self, I>>;
+        /// The additional deposit held per byte of claim.
+        #[pallet::constant]
+        type ClaimDepositPerByte: Get<BalanceOf<Self, I>>;
+        /// Whether owners may reassign a claim with `transfer_claim` without the receiver
+        /// accepting it first.
+        #[pallet::constant]
+        type AllowDirectTransfer: Get<bool>;
+        /// The maximum number of claims that may expire in the same block.
+        #[pallet::constant]
+        type MaxExpiriesPerBlock: Get<u32>;
+        /// The maximum length of each metadata field.
+        #[pallet::constant]
+        type MaxMetadataLength: Get<u32>;
+        /// The number of provenance records kept per claim; the oldest are dropped first.
+        #[pallet::constant]
+        type MaxHistoryLength: Get<u32>;
+        /// The maximum number of sibling hashes in a Merkle inclusion proof.
+        #[pallet::constant]
+        type MaxMerkleProofLength: Get<u32>;
+        /// The signature owners use to authorize sponsored claims.
+        type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+        /// The public key of an `OffchainSignature`.
+        type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+        /// The origin that can freeze claims and transfer or revoke them on behalf of their
+        /// owner, to resolve disputes.
+        type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// The maximum length of the reason given for a privileged action.
+        #[pallet::constant]
+        type MaxReasonLength: Get<u32>;
+        /// The maximum number of claims a single account may own, hash claims included.
+        #[pallet::constant]
+        type MaxClaimsPerAccount: Get<u32>;
+        /// The maximum number of claims in the registry, hash claims included, if any.
+        #[pallet::constant]
+        type MaxTotalClaims: Get<Option<u32>>;
+        /// The maximum number of claims in a single batch call.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+        /// The maximum length of the content passed to `create_claim_with_content`.
+        #[pallet::constant]
+        type MaxContentLength: Get<u32>;
+        /// Identifies an NFT collection in `Nfts`.
+        type NftCollectionId: Member + Parameter + MaxEncodedLen + Copy;
+        /// Identifies an NFT within its collection in `Nfts`.
+        type NftId: Member + Parameter + MaxEncodedLen + Copy + Incrementable;
+        /// The ID of the first NFT minted for a claim.
+        ///
+        /// Instances minting into the same collection need IDs far enough apart that their
+        /// ranges never meet.
+        #[pallet::constant]
+        type FirstNftId: Get<Self::NftId>;
+        /// The item configuration type of `Nfts`.
+        type NftItemConfig;
+        /// The configuration of the NFTs minted for claims.
+        type ClaimNftConfig: Get<Self::NftItemConfig>;
+        /// The NFTs mirroring claims, usually `pallet_nfts`.
+        type Nfts: NftInspect<Self::AccountId, CollectionId = Self::NftCollectionId, ItemId = Self::NftId>
+            + NftMutate<Self::AccountId, Self::NftItemConfig>
+            + NftTransfer<Self::AccountId>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+        /// Creates signed claim payloads for the benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<
+            Self::OffchainPublic,
+            Self::AccountId,
+            Self::OffchainSignature,
+            Self::NftCollectionId,
+        >;
     }
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
     #[pallet::pallet]
-    pub struct Pallet<T>(_);
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T, I = ()>(_);
+
+    /// A reason for the pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason<I: 'static = ()> {
+        /// The funds are held as deposit for a claim.
+        #[codec(index = 0)]
+        ClaimDeposit,
+    }
+
+    #[pallet::storage]
+    pub type Proofs<T: Config<I>, I: 'static = ()> =
+        CountedStorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxClaimLenth>, ClaimInfoOf<T>>;
+
+    /// The account holding the deposit for each claim and the amount held, released in full
+    /// when the claim is revoked.
+    ///
+    /// This is the owner, except for sponsored claims whose deposit stays with the relayer for
+    /// as long as the claim exists.
+    #[pallet::storage]
+    pub type Deposits<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLenth>,
+        (T::AccountId, BalanceOf<T, I>),
+    >;
+
+    /// The number of claims owned by each account, kept in sync with `ClaimsOf` and
+    /// `HashClaimsOf`.
+    #[pallet::storage]
+    pub type ClaimCount<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Index of the claims owned by each account, kept in sync with `Proofs`.
+    #[pallet::storage]
+    pub type ClaimsOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLenth>,
+        (),
+    >;
+
+    /// Index of the hash claims owned by each account, kept in sync with `HashProofs`.
+    #[pallet::storage]
+    pub type HashClaimsOf<T: Config<I>, I: 'static = ()> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::Hash, ()>;
+
+    /// Claims offered to a new owner, with the block after which the offer can no longer be
+    /// accepted. Anyone may remove an expired offer with `cancel_offer`.
+    #[pallet::storage]
+    pub type PendingTransfers<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLenth>,
+        (T::AccountId, Option<BlockNumberFor<T>>),
+    >;
+
+    /// The block at which each expiring claim is removed.
+    #[pallet::storage]
+    pub type ClaimExpiry<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxClaimLenth>, BlockNumberFor<T>>;
+
+    /// The claims to remove in `on_initialize` of each block.
+    #[pallet::storage]
+    pub type ExpiryQueue<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<BoundedVec<u8, T::MaxClaimLenth>, T::MaxExpiriesPerBlock>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    pub type Metadata<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLenth>,
+        ClaimMetadata<T::MaxMetadataLength>,
+    >;
+
+    /// The ownership history of each claim. It outlives the claim so that revoked claims can
+    /// still be audited.
+    #[pallet::storage]
+    pub type History<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLenth>,
+        BoundedVec<ProvenanceRecordOf<T>, T::MaxHistoryLength>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    pub type HashProofs<T: Config<I>, I: 'static = ()> =
+        CountedStorageMap<_, Blake2_128Concat, T::Hash, ClaimInfoOf<T>>;
+
+    /// The deposit held for each hash claim.
+    #[pallet::storage]
+    pub type HashDeposits<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::Hash, BalanceOf<T, I>>;
+
+    #[pallet::storage]
+    pub type HashMetadata<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::Hash, ClaimMetadata<T::MaxMetadataLength>>;
+
+    #[pallet::storage]
+    pub type HashHistory<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        BoundedVec<ProvenanceRecordOf<T>, T::MaxHistoryLength>,
+        ValueQuery,
+    >;
+
+    /// Hash claims that are the root of a Merkle tree of documents.
+    #[pallet::storage]
+    pub type MerkleRoots<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::Hash, ()>;
+
+    /// The operator each owner approved to manage a single claim. Cleared when the claim changes
+    /// hands.
+    #[pallet::storage]
+    pub type ClaimOperators<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxClaimLenth>, T::AccountId>;
+
+    /// Operators allowed to manage every claim of an owner.
+    #[pallet::storage]
+    pub type Operators<T: Config<I>, I: 'static = ()> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, ()>;
+
+    /// Claims under dispute, which their owner and operators cannot change.
+    #[pallet::storage]
+    pub type Frozen<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxClaimLenth>, ()>;
+
+    /// Hash claims under dispute, which their owner cannot change.
+    #[pallet::storage]
+    pub type HashFrozen<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::Hash, ()>;
 
+    /// The collection in which an NFT is minted for every new claim, if any.
     #[pallet::storage]
-    pub type Proofs<T: Config> = StorageMap<
+    pub type NftCollection<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, T::NftCollectionId, OptionQuery>;
+
+    /// The ID of the next NFT minted for a claim, `FirstNftId` until one is minted.
+    #[pallet::storage]
+    pub type NextNftId<T: Config<I>, I: 'static = ()> = StorageValue<_, T::NftId, OptionQuery>;
+
+    /// The NFT mirroring each claim, as `(collection, item)`.
+    #[pallet::storage]
+    pub type ClaimNfts<T: Config<I>, I: 'static = ()> = StorageMap<
         _,
         Blake2_128Concat,
         BoundedVec<u8, T::MaxClaimLenth>,
-        (T::AccountId, BlockNumberFor<T>),
+        (T::NftCollectionId, T::NftId),
     >;
 
+    /// The nonce each account must include in its next signed claim payload.
+    #[pallet::storage]
+    pub type SignerNonces<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        ClaimCreated(T::AccountId, BoundedVec<u8, T::MaxClaimLenth>),
+    pub enum Event<T: Config<I>, I: 'static = ()> {
+        /// A claim was created. \[owner, claim, timestamp\]
+        ClaimCreated(T::AccountId, BoundedVec<u8, T::MaxClaimLenth>, u64),
         ClaimRevoked(T::AccountId, BoundedVec<u8, T::MaxClaimLenth>),
-        ClaimTransfered(T::AccountId, T::AccountId, BoundedVec<u8, T::MaxClaimLenth>),
+        /// A claim changed owner. \[from, to, claim, timestamp\]
+        ClaimTransfered(
+            T::AccountId,
+            T::AccountId,
+            BoundedVec<u8, T::MaxClaimLenth>,
+            u64,
+        ),
+        ClaimOffered(
+            T::AccountId,
+            T::AccountId,
+            BoundedVec<u8, T::MaxClaimLenth>,
+            Option<BlockNumberFor<T>>,
+        ),
+        OfferAccepted(T::AccountId, BoundedVec<u8, T::MaxClaimLenth>),
+        OfferCancelled(T::AccountId, BoundedVec<u8, T::MaxClaimLenth>),
+        ClaimExpired(T::AccountId, BoundedVec<u8, T::MaxClaimLenth>),
+        /// An expired claim could not be removed and no longer expires. \[claim, error\]
+        ExpiryFailed(BoundedVec<u8, T::MaxClaimLenth>, DispatchError),
+        ClaimMetadataSet(T::AccountId, BoundedVec<u8, T::MaxClaimLenth>),
+        /// A hash claim was created. \[owner, hash, timestamp\]
+        HashClaimCreated(T::AccountId, T::Hash, u64),
+        HashClaimRevoked(T::AccountId, T::Hash),
+        /// A hash claim changed owner. \[from, to, hash, timestamp\]
+        HashClaimTransfered(T::AccountId, T::AccountId, T::Hash, u64),
+        HashClaimMetadataSet(T::AccountId, T::Hash),
+        MerkleClaimCreated(T::AccountId, T::Hash),
+        /// A leaf was proven to be part of a Merkle claim. \[root, leaf\]
+        InclusionVerified(T::Hash, T::Hash),
+        /// A relayer created a claim on behalf of its owner. \[relayer, owner, claim\]
+        ClaimSponsored(T::AccountId, T::AccountId, BoundedVec<u8, T::MaxClaimLenth>),
+        /// An owner approved an operator for one claim, or all of them if no claim is given.
+        /// \[owner, operator, claim\]
+        OperatorApproved(
+            T::AccountId,
+            T::AccountId,
+            Option<BoundedVec<u8, T::MaxClaimLenth>>,
+        ),
+        /// \[owner, operator, claim\]
+        OperatorRevoked(
+            T::AccountId,
+            T::AccountId,
+            Option<BoundedVec<u8, T::MaxClaimLenth>>,
+        ),
+        /// \[claim, reason\]
+        ClaimFrozen(
+            BoundedVec<u8, T::MaxClaimLenth>,
+            BoundedVec<u8, T::MaxReasonLength>,
+        ),
+        /// \[claim, reason\]
+        ClaimUnfrozen(
+            BoundedVec<u8, T::MaxClaimLenth>,
+            BoundedVec<u8, T::MaxReasonLength>,
+        ),
+        /// \[from, to, claim, reason\]
+        ClaimForceTransferred(
+            T::AccountId,
+            T::AccountId,
+            BoundedVec<u8, T::MaxClaimLenth>,
+            BoundedVec<u8, T::MaxReasonLength>,
+        ),
+        /// \[owner, claim, reason\]
+        ClaimForceRevoked(
+            T::AccountId,
+            BoundedVec<u8, T::MaxClaimLenth>,
+            BoundedVec<u8, T::MaxReasonLength>,
+        ),
+        /// \[hash, reason\]
+        HashClaimFrozen(T::Hash, BoundedVec<u8, T::MaxReasonLength>),
+        /// \[hash, reason\]
+        HashClaimUnfrozen(T::Hash, BoundedVec<u8, T::MaxReasonLength>),
+        /// \[from, to, hash, reason\]
+        HashClaimForceTransferred(
+            T::AccountId,
+            T::AccountId,
+            T::Hash,
+            BoundedVec<u8, T::MaxReasonLength>,
+        ),
+        /// \[owner, hash, reason\]
+        HashClaimForceRevoked(T::AccountId, T::Hash, BoundedVec<u8, T::MaxReasonLength>),
+        /// A claim of a best-effort batch failed and was skipped. \[claim, error\]
+        BatchItemFailed(BoundedVec<u8, T::MaxClaimLenth>, DispatchError),
+        /// \[succeeded, failed\]
+        BatchCompleted(u32, u32),
+        /// \[collection\]
+        NftCollectionSet(Option<T::NftCollectionId>),
+        /// An NFT was minted to mirror a claim. \[claim, collection, item\]
+        ClaimNftMinted(
+            BoundedVec<u8, T::MaxClaimLenth>,
+            T::NftCollectionId,
+            T::NftId,
+        ),
+        /// The NFT of a claim could not follow a forced change and no longer mirrors it.
+        /// \[claim, error\]
+        ClaimNftDetached(BoundedVec<u8, T::MaxClaimLenth>, DispatchError),
+        DepositHeld(T::AccountId, BalanceOf<T, I>),
+        DepositReleased(T::AccountId, BalanceOf<T, I>),
+        DepositTransfered(T::AccountId, T::AccountId, BalanceOf<T, I>),
     }
 
     #[pallet::error]
-    pub enum Error<T> {
+    pub enum Error<T, I = ()> {
         ProofAlreadyExist,
         ClaimNotExist,
         NotClaimOwner,
+        /// The account cannot afford the claim deposit.
+        InsufficientBalance,
+        /// The receiving account cannot take over the claim deposit.
+        CannotTransferDeposit,
+        /// Claims must be offered and accepted instead of transferred directly.
+        DirectTransferDisabled,
+        /// There is no pending offer for the claim.
+        OfferNotExist,
+        /// The caller is not the account the claim was offered to.
+        NotOfferRecipient,
+        /// The offer has expired.
+        OfferExpired,
+        /// The expiry block is not in the future.
+        InvalidExpiry,
+        /// Too many claims already expire at the requested block.
+        ExpiryQueueFull,
+        /// The root is not a registered Merkle claim.
+        MerkleRootNotExist,
+        /// The proof does not lead from the leaf to the root.
+        InvalidMerkleProof,
+        /// The signature does not match the payload and owner.
+        InvalidSignature,
+        /// The deadline of the signed payload has passed.
+        SignatureExpired,
+        /// The caller is an operator of the claim, but its approval does not cover the action.
+        NotApproved,
+        /// The operator was not approved.
+        ApprovalNotExist,
+        /// The claim is frozen pending a dispute.
+        ClaimFrozen,
+        /// The claim is not frozen.
+        ClaimNotFrozen,
+        /// The account already owns `MaxClaimsPerAccount` claims, or the registry holds
+        /// `MaxTotalClaims`.
+        TooManyClaims,
+        /// Every NFT ID has been used.
+        NftIdsExhausted,
+    }
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+        /// Claims to register at genesis, with their owners. No deposit is held for them.
+        ///
+        /// The genesis block carries no time, so these claims have a zero timestamp, like the
+        /// claims created before timestamps were recorded.
+        pub claims: Vec<(Vec<u8>, T::AccountId)>,
+        #[serde(skip)]
+        pub _phantom: PhantomData<I>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config<I>, I: 'static> BuildGenesisConfig for GenesisConfig<T, I> {
+        fn build(&self) {
+            for (claim, owner) in &self.claims {
+                let claim: BoundedVec<u8, T::MaxClaimLenth> = claim
+                    .clone()
+                    .try_into()
+                    .expect("genesis claim is longer than MaxClaimLenth");
+                assert!(
+                    !Proofs::<T, I>::contains_key(&claim),
+                    "duplicate claim in genesis"
+                );
+                Pallet::<T, I>::ensure_quota(owner, true)
+                    .expect("genesis claims exceed MaxClaimsPerAccount or MaxTotalClaims");
+
+                Proofs::<T, I>::insert(
+                    &claim,
+                    ClaimInfo {
+                        owner: owner.clone(),
+                        block_number: frame_system::Pallet::<T>::block_number(),
+                        timestamp: 0,
+                    },
+                );
+                ClaimsOf::<T, I>::insert(owner, &claim, ());
+                ClaimCount::<T, I>::mutate(owner, |count| count.saturating_inc());
+                History::<T, I>::mutate(&claim, |history| {
+                    Pallet::<T, I>::record(history, owner.clone(), ClaimAction::Created)
+                });
+            }
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let queue = ExpiryQueue::<T, I>::take(n);
+            let weight = T::WeightInfo::on_initialize(queue.len() as u32);
+
+            for claim in queue {
+                if ClaimExpiry::<T, I>::get(&claim) != Some(n) {
+                    continue;
+                }
+                let Some(ClaimInfo { owner, .. }) = Proofs::<T, I>::get(&claim) else {
+                    continue;
+                };
+                // Keep a frozen claim for its dispute, and roll back a claim that cannot be
+                // removed, so that it is kept whole.
+                let removed = with_storage_layer(|| {
+                    Self::ensure_not_frozen(&claim)?;
+                    Self::do_remove(&owner, &claim, ClaimAction::Expired, false)
+                });
+                match removed {
+                    Ok(()) => Self::deposit_event(Event::ClaimExpired(owner, claim)),
+                    Err(error) => {
+                        ClaimExpiry::<T, I>::remove(&claim);
+                        Self::deposit_event(Event::ExpiryFailed(claim, error));
+                    }
+                }
+            }
+
+            weight
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::WeightInfo::on_initialize(T::MaxExpiriesPerBlock::get())
+                    .all_lte(T::BlockWeights::get().max_block),
+                "expiring MaxExpiriesPerBlock claims does not fit in a block"
+            );
+        }
     }
 
     #[pallet::call]
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
         #[pallet::call_index(0)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::create_claim())]
         pub fn create_claim(
             origin: OriginFor<T>,
             claim: BoundedVec<u8, T::MaxClaimLenth>,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Self::do_create(&sender, sender.clone(), claim, expires_at)
+        }
+
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::revoke_claim())]
+        pub fn revoke_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLenth>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Self::do_revoke(&sender, claim)
+        }
+
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::transfer_claim())]
+        pub fn transfer_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLenth>,
+            to: T::AccountId,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
             ensure!(
-                !Proofs::<T>::contains_key(&claim),
-                Error::<T>::ProofAlreadyExist
+                T::AllowDirectTransfer::get(),
+                Error::<T, I>::DirectTransferDisabled
             );
-            Proofs::<T>::insert(
+            let from = Self::ensure_approved(&sender, &claim)?;
+
+            Self::do_transfer(from, to, claim, false)
+        }
+
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::create_hash_claim())]
+        pub fn create_hash_claim(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Self::do_create_hash(sender, hash)
+        }
+
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::revoke_hash_claim())]
+        pub fn revoke_hash_claim(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let owner = HashProofs::<T, I>::get(&hash)
+                .ok_or(Error::<T, I>::ClaimNotExist)?
+                .owner;
+            ensure!(sender == owner, Error::<T, I>::NotClaimOwner);
+            Self::ensure_hash_not_frozen(&hash)?;
+            Self::do_remove_hash(&sender, &hash)?;
+
+            Self::deposit_event(Event::HashClaimRevoked(sender, hash));
+            Ok(().into())
+        }
+
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::transfer_hash_claim())]
+        pub fn transfer_hash_claim(
+            origin: OriginFor<T>,
+            hash: T::Hash,
+            to: T::AccountId,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            let owner = HashProofs::<T, I>::get(&hash)
+                .ok_or(Error::<T, I>::ClaimNotExist)?
+                .owner;
+            ensure!(from == owner, Error::<T, I>::NotClaimOwner);
+            Self::ensure_hash_not_frozen(&hash)?;
+
+            Self::do_transfer_hash(from, to, hash)
+        }
+
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::offer_claim())]
+        pub fn offer_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLenth>,
+            to: T::AccountId,
+            expires_in: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            Self::ensure_owner(&from, &claim)?;
+            Self::ensure_not_frozen(&claim)?;
+
+            let expires_at = expires_in
+                .map(|blocks| frame_system::Pallet::<T>::block_number().saturating_add(blocks));
+            PendingTransfers::<T, I>::insert(&claim, (to.clone(), expires_at));
+
+            Self::deposit_event(Event::ClaimOffered(from, to, claim, expires_at));
+            Ok(().into())
+        }
+
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::accept_claim())]
+        pub fn accept_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLenth>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let (to, expires_at) =
+                PendingTransfers::<T, I>::get(&claim).ok_or(Error::<T, I>::OfferNotExist)?;
+            ensure!(sender == to, Error::<T, I>::NotOfferRecipient);
+            if let Some(expires_at) = expires_at {
+                ensure!(
+                    frame_system::Pallet::<T>::block_number() <= expires_at,
+                    Error::<T, I>::OfferExpired
+                );
+            }
+            let owner = Proofs::<T, I>::get(&claim)
+                .ok_or(Error::<T, I>::ClaimNotExist)?
+                .owner;
+            Self::ensure_not_frozen(&claim)?;
+
+            Self::do_transfer(owner, to, claim.clone(), false)?;
+
+            Self::deposit_event(Event::OfferAccepted(sender, claim));
+            Ok(().into())
+        }
+
+        /// Withdraw the offer of `claim`.
+        ///
+        /// Only the owner may withdraw a live offer, but anyone may clean up an expired one.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::cancel_offer())]
+        pub fn cancel_offer(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLenth>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let (_, expires_at) =
+                PendingTransfers::<T, I>::get(&claim).ok_or(Error::<T, I>::OfferNotExist)?;
+            let expired = expires_at.map_or(false, |expires_at| {
+                frame_system::Pallet::<T>::block_number() > expires_at
+            });
+            if !expired {
+                Self::ensure_owner(&sender, &claim)?;
+            }
+            PendingTransfers::<T, I>::remove(&claim);
+
+            Self::deposit_event(Event::OfferCancelled(sender, claim));
+            Ok(().into())
+        }
+
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_claim_metadata())]
+        pub fn set_claim_metadata(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLenth>,
+            content_type: BoundedVec<u8, T::MaxMetadataLength>,
+            uri: BoundedVec<u8, T::MaxMetadataLength>,
+            description: BoundedVec<u8, T::MaxMetadataLength>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_owner(&sender, &claim)?;
+            Self::ensure_not_frozen(&claim)?;
+
+            Metadata::<T, I>::insert(
                 &claim,
-                (sender.clone(), frame_system::Pallet::<T>::block_number()),
+                ClaimMetadata {
+                    content_type,
+                    uri,
+                    description,
+                },
             );
 
-            Self::deposit_event(Event::ClaimCreated(sender, claim));
+            Self::deposit_event(Event::ClaimMetadataSet(sender, claim));
             Ok(().into())
         }
 
-        #[pallet::call_index(1)]
-        #[pallet::weight(0)]
-        pub fn revoke_claim(
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::set_hash_claim_metadata())]
+        pub fn set_hash_claim_metadata(
+            origin: OriginFor<T>,
+            hash: T::Hash,
+            content_type: BoundedVec<u8, T::MaxMetadataLength>,
+            uri: BoundedVec<u8, T::MaxMetadataLength>,
+            description: BoundedVec<u8, T::MaxMetadataLength>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let owner = HashProofs::<T, I>::get(&hash)
+                .ok_or(Error::<T, I>::ClaimNotExist)?
+                .owner;
+            ensure!(sender == owner, Error::<T, I>::NotClaimOwner);
+            Self::ensure_hash_not_frozen(&hash)?;
+
+            HashMetadata::<T, I>::insert(
+                &hash,
+                ClaimMetadata {
+                    content_type,
+                    uri,
+                    description,
+                },
+            );
+
+            Self::deposit_event(Event::HashClaimMetadataSet(sender, hash));
+            Ok(().into())
+        }
+
+        /// Register `root` as a hash claim covering every leaf of its Merkle tree, built as
+        /// described in `merkle_root`.
+        ///
+        /// The claim is revoked and transferred like any other hash claim.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::create_merkle_claim())]
+        pub fn create_merkle_claim(origin: OriginFor<T>, root: T::Hash) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Self::do_create_hash(sender.clone(), root)?;
+            MerkleRoots::<T, I>::insert(&root, ());
+
+            Self::deposit_event(Event::MerkleClaimCreated(sender, root));
+            Ok(().into())
+        }
+
+        /// Check on chain that `leaf` is part of the Merkle claim `root`, leaving a record of the
+        /// check in the events. Nothing is stored.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::verify_inclusion(proof.len() as u32))]
+        pub fn verify_inclusion(
+            origin: OriginFor<T>,
+            root: T::Hash,
+            leaf: T::Hash,
+            proof: BoundedVec<T::Hash, T::MaxMerkleProofLength>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(
+                MerkleRoots::<T, I>::contains_key(&root),
+                Error::<T, I>::MerkleRootNotExist
+            );
+            ensure!(
+                Self::merkle_root(leaf, &proof) == root,
+                Error::<T, I>::InvalidMerkleProof
+            );
+
+            Self::deposit_event(Event::InclusionVerified(root, leaf));
+            Ok(().into())
+        }
+
+        /// Create `claim` for `owner`, who authorized it by signing a `SignedClaimPayload`.
+        ///
+        /// The caller submits the transaction, pays its fees and holds the claim deposit, so
+        /// owners do not need any funds of their own.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::create_claim_signed())]
+        pub fn create_claim_signed(
             origin: OriginFor<T>,
             claim: BoundedVec<u8, T::MaxClaimLenth>,
+            owner: T::AccountId,
+            signature: T::OffchainSignature,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let relayer = ensure_signed(origin)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= deadline,
+                Error::<T, I>::SignatureExpired
+            );
+
+            let nonce = SignerNonces::<T, I>::get(&owner);
+            let payload = Self::signed_claim_payload(claim.clone(), owner.clone(), deadline);
+            ensure!(
+                signature.verify(&payload.encode()[..], &owner),
+                Error::<T, I>::InvalidSignature
+            );
+            SignerNonces::<T, I>::insert(&owner, nonce.wrapping_add(1));
+
+            Self::do_create(&relayer, owner.clone(), claim.clone(), None)?;
+
+            Self::deposit_event(Event::ClaimSponsored(relayer, owner, claim));
+            Ok(().into())
+        }
+
+        /// Let `operator` transfer and revoke `claim` on the caller's behalf, or every claim the
+        /// caller owns now or later if `claim` is `None`.
+        ///
+        /// A claim has at most one operator of its own; approving another replaces it.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::approve_operator())]
+        pub fn approve_operator(
+            origin: OriginFor<T>,
+            operator: T::AccountId,
+            claim: Option<BoundedVec<u8, T::MaxClaimLenth>>,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
-            let (owner, _) = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
-            ensure!(sender == owner, Error::<T>::NotClaimOwner);
-            Proofs::<T>::remove(&claim);
+            match &claim {
+                Some(claim) => {
+                    Self::ensure_owner(&sender, claim)?;
+                    Self::ensure_not_frozen(claim)?;
+                    ClaimOperators::<T, I>::insert(claim, &operator);
+                }
+                None => Operators::<T, I>::insert(&sender, &operator, ()),
+            }
 
-            Self::deposit_event(Event::ClaimRevoked(sender, claim));
+            Self::deposit_event(Event::OperatorApproved(sender, operator, claim));
             Ok(().into())
         }
 
-        #[pallet::call_index(2)]
-        #[pallet::weight(0)]
-        pub fn transfer_claim(
+        /// Withdraw an approval given with `approve_operator`.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::revoke_operator())]
+        pub fn revoke_operator(
+            origin: OriginFor<T>,
+            operator: T::AccountId,
+            claim: Option<BoundedVec<u8, T::MaxClaimLenth>>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            match &claim {
+                Some(claim) => {
+                    Self::ensure_owner(&sender, claim)?;
+                    ensure!(
+                        ClaimOperators::<T, I>::get(claim) == Some(operator.clone()),
+                        Error::<T, I>::ApprovalNotExist
+                    );
+                    ClaimOperators::<T, I>::remove(claim);
+                }
+                None => {
+                    ensure!(
+                        Operators::<T, I>::contains_key(&sender, &operator),
+                        Error::<T, I>::ApprovalNotExist
+                    );
+                    Operators::<T, I>::remove(&sender, &operator);
+                }
+            }
+
+            Self::deposit_event(Event::OperatorRevoked(sender, operator, claim));
+            Ok(().into())
+        }
+
+        /// Freeze a disputed claim so that its owner and operators can no longer change it.
+        ///
+        /// A claim that is frozen when it would expire is kept and no longer expires.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::freeze_claim())]
+        pub fn freeze_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLenth>,
+            reason: BoundedVec<u8, T::MaxReasonLength>,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            ensure!(
+                Proofs::<T, I>::contains_key(&claim),
+                Error::<T, I>::ClaimNotExist
+            );
+            Self::ensure_not_frozen(&claim)?;
+            Frozen::<T, I>::insert(&claim, ());
+
+            Self::deposit_event(Event::ClaimFrozen(claim, reason));
+            Ok(().into())
+        }
+
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::unfreeze_claim())]
+        pub fn unfreeze_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLenth>,
+            reason: BoundedVec<u8, T::MaxReasonLength>,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            ensure!(
+                Frozen::<T, I>::take(&claim).is_some(),
+                Error::<T, I>::ClaimNotFrozen
+            );
+
+            Self::deposit_event(Event::ClaimUnfrozen(claim, reason));
+            Ok(().into())
+        }
+
+        /// Hand a claim to `to` regardless of its owner, lifting any freeze.
+        ///
+        /// The deposit moves with the claim, as in `transfer_claim`.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::force_transfer_claim())]
+        pub fn force_transfer_claim(
             origin: OriginFor<T>,
             claim: BoundedVec<u8, T::MaxClaimLenth>,
             to: T::AccountId,
+            reason: BoundedVec<u8, T::MaxReasonLength>,
         ) -> DispatchResult {
-            let from = ensure_signed(origin)?;
-            let (owner, block_number) =
-                Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
-            ensure!(from == owner, Error::<T>::NotClaimOwner);
-            Proofs::<T>::insert(&claim, (to.clone(), block_number));
+            T::ForceOrigin::ensure_origin(origin)?;
+            let owner = Proofs::<T, I>::get(&claim)
+                .ok_or(Error::<T, I>::ClaimNotExist)?
+                .owner;
+            Self::do_transfer(owner.clone(), to.clone(), claim.clone(), true)?;
+            Frozen::<T, I>::remove(&claim);
+
+            Self::deposit_event(Event::ClaimForceTransferred(owner, to, claim, reason));
+            Ok(().into())
+        }
+
+        /// Remove a claim regardless of its owner, releasing its deposit.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::force_revoke_claim())]
+        pub fn force_revoke_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLenth>,
+            reason: BoundedVec<u8, T::MaxReasonLength>,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            let owner = Proofs::<T, I>::get(&claim)
+                .ok_or(Error::<T, I>::ClaimNotExist)?
+                .owner;
+            Self::do_remove(&owner, &claim, ClaimAction::Revoked, true)?;
 
-            Self::deposit_event(Event::ClaimTransfered(from, to, claim));
+            Self::deposit_event(Event::ClaimForceRevoked(owner, claim, reason));
             Ok(().into())
         }
+
+        /// Create several claims at once.
+        ///
+        /// With `atomic` set, any failure reverts the whole batch. Otherwise each failing claim
+        /// is skipped and reported with `BatchItemFailed`.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::create_claims(claims.len() as u32))]
+        pub fn create_claims(
+            origin: OriginFor<T>,
+            claims: BoundedVec<BoundedVec<u8, T::MaxClaimLenth>, T::MaxBatchSize>,
+            atomic: bool,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Self::do_batch(claims, atomic, |claim| {
+                Self::do_create(&sender, sender.clone(), claim, None)
+            })
+        }
+
+        /// Revoke several claims at once, with the same `atomic` semantics as `create_claims`.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::revoke_claims(claims.len() as u32))]
+        pub fn revoke_claims(
+            origin: OriginFor<T>,
+            claims: BoundedVec<BoundedVec<u8, T::MaxClaimLenth>, T::MaxBatchSize>,
+            atomic: bool,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Self::do_batch(claims, atomic, |claim| Self::do_revoke(&sender, claim))
+        }
+
+        /// Transfer several claims to `to` at once, with the same `atomic` semantics as
+        /// `create_claims`.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::transfer_claims(claims.len() as u32))]
+        pub fn transfer_claims(
+            origin: OriginFor<T>,
+            claims: BoundedVec<BoundedVec<u8, T::MaxClaimLenth>, T::MaxBatchSize>,
+            to: T::AccountId,
+            atomic: bool,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(
+                T::AllowDirectTransfer::get(),
+                Error::<T, I>::DirectTransferDisabled
+            );
+
+            Self::do_batch(claims, atomic, |claim| {
+                let from = Self::ensure_approved(&sender, &claim)?;
+                Self::do_transfer(from, to.clone(), claim, false)
+            })
+        }
+
+        /// Create a hash claim for `content`, keeping only its hash on chain.
+        ///
+        /// The content itself is written to the off-chain database of nodes running with
+        /// off-chain indexing enabled, under [`offchain_content_key`]. It stays there when the
+        /// claim is revoked.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::create_claim_with_content(content.len() as u32))]
+        pub fn create_claim_with_content(
+            origin: OriginFor<T>,
+            content: BoundedVec<u8, T::MaxContentLength>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let hash = Self::hash_of(&content);
+
+            Self::do_create_hash(sender, hash)?;
+            sp_io::offchain_index::set(&offchain_content_key(&hash), &content);
+            Ok(())
+        }
+
+        /// Set the collection in which an NFT is minted for every new claim, or stop minting
+        /// them with `None`.
+        ///
+        /// The collection must allow its items to be transferred, and the PoE pallet must be
+        /// the only one minting into it. Its owner pays the item deposits. The NFTs are locked
+        /// so that they only change hands with their claim; their holders may still burn them.
+        /// Existing claims keep their NFTs, if any.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::set_nft_collection())]
+        pub fn set_nft_collection(
+            origin: OriginFor<T>,
+            collection: Option<T::NftCollectionId>,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            NftCollection::<T, I>::set(collection);
+
+            Self::deposit_event(Event::NftCollectionSet(collection));
+            Ok(())
+        }
+
+        /// Freeze a disputed hash claim so that its owner can no longer change it.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::freeze_hash_claim())]
+        pub fn freeze_hash_claim(
+            origin: OriginFor<T>,
+            hash: T::Hash,
+            reason: BoundedVec<u8, T::MaxReasonLength>,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            ensure!(
+                HashProofs::<T, I>::contains_key(&hash),
+                Error::<T, I>::ClaimNotExist
+            );
+            Self::ensure_hash_not_frozen(&hash)?;
+            HashFrozen::<T, I>::insert(&hash, ());
+
+            Self::deposit_event(Event::HashClaimFrozen(hash, reason));
+            Ok(())
+        }
+
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::unfreeze_hash_claim())]
+        pub fn unfreeze_hash_claim(
+            origin: OriginFor<T>,
+            hash: T::Hash,
+            reason: BoundedVec<u8, T::MaxReasonLength>,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            ensure!(
+                HashFrozen::<T, I>::take(&hash).is_some(),
+                Error::<T, I>::ClaimNotFrozen
+            );
+
+            Self::deposit_event(Event::HashClaimUnfrozen(hash, reason));
+            Ok(())
+        }
+
+        /// Hand a hash claim to `to` regardless of its owner, lifting any freeze.
+        ///
+        /// The deposit moves with the claim, as in `transfer_hash_claim`.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::force_transfer_hash_claim())]
+        pub fn force_transfer_hash_claim(
+            origin: OriginFor<T>,
+            hash: T::Hash,
+            to: T::AccountId,
+            reason: BoundedVec<u8, T::MaxReasonLength>,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            let owner = HashProofs::<T, I>::get(&hash)
+                .ok_or(Error::<T, I>::ClaimNotExist)?
+                .owner;
+            Self::do_transfer_hash(owner.clone(), to.clone(), hash)?;
+            HashFrozen::<T, I>::remove(&hash);
+
+            Self::deposit_event(Event::HashClaimForceTransferred(owner, to, hash, reason));
+            Ok(())
+        }
+
+        /// Remove a hash claim regardless of its owner, releasing its deposit.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::force_revoke_hash_claim())]
+        pub fn force_revoke_hash_claim(
+            origin: OriginFor<T>,
+            hash: T::Hash,
+            reason: BoundedVec<u8, T::MaxReasonLength>,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            let owner = HashProofs::<T, I>::get(&hash)
+                .ok_or(Error::<T, I>::ClaimNotExist)?
+                .owner;
+            Self::do_remove_hash(&owner, &hash)?;
+
+            Self::deposit_event(Event::HashClaimForceRevoked(owner, hash, reason));
+            Ok(())
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// The deposit required for a claim of `len` bytes.
+        pub fn claim_deposit(len: u32) -> BalanceOf<T, I> {
+            T::ClaimDepositBase::get()
+                .saturating_add(T::ClaimDepositPerByte::get().saturating_mul(len.into()))
+        }
+
+        /// List the claims owned by `owner`, at most `limit` at a time.
+        ///
+        /// Pass the returned cursor back as `start_after` to fetch the next page; `None` means
+        /// there are no more claims.
+        pub fn claims_of(
+            owner: &T::AccountId,
+            start_after: Option<BoundedVec<u8, T::MaxClaimLenth>>,
+            limit: u32,
+        ) -> (
+            Vec<BoundedVec<u8, T::MaxClaimLenth>>,
+            Option<BoundedVec<u8, T::MaxClaimLenth>>,
+        ) {
+            let iter = match start_after {
+                Some(last) => ClaimsOf::<T, I>::iter_key_prefix_from(
+                    owner,
+                    ClaimsOf::<T, I>::hashed_key_for(owner, &last),
+                ),
+                None => ClaimsOf::<T, I>::iter_key_prefix(owner),
+            };
+            Self::page(iter, limit)
+        }
+
+        /// List the hash claims owned by `owner`, at most `limit` at a time, like `claims_of`.
+        pub fn hash_claims_of(
+            owner: &T::AccountId,
+            start_after: Option<T::Hash>,
+            limit: u32,
+        ) -> (Vec<T::Hash>, Option<T::Hash>) {
+            let iter = match start_after {
+                Some(last) => HashClaimsOf::<T, I>::iter_key_prefix_from(
+                    owner,
+                    HashClaimsOf::<T, I>::hashed_key_for(owner, &last),
+                ),
+                None => HashClaimsOf::<T, I>::iter_key_prefix(owner),
+            };
+            Self::page(iter, limit)
+        }
+
+        /// Take at most `limit` keys from `iter`, with the last one as the cursor of the next
+        /// page if there are more.
+        fn page<K: Clone>(mut iter: impl Iterator<Item = K>, limit: u32) -> (Vec<K>, Option<K>) {
+            let keys: Vec<_> = iter.by_ref().take(limit as usize).collect();
+            let cursor = if keys.len() == limit as usize && iter.next().is_some() {
+                keys.last().cloned()
+            } else {
+                None
+            };
+
+            (keys, cursor)
+        }
+
+        /// Whether `operator` may transfer and revoke `claim` on behalf of `owner`.
+        pub fn is_operator(
+            owner: &T::AccountId,
+            operator: &T::AccountId,
+            claim: &BoundedVec<u8, T::MaxClaimLenth>,
+        ) -> bool {
+            Operators::<T, I>::contains_key(owner, operator)
+                || ClaimOperators::<T, I>::get(claim).as_ref() == Some(operator)
+        }
+
+        /// Check that `who` owns `claim` or is approved to manage it, returning the owner.
+        fn ensure_approved(
+            who: &T::AccountId,
+            claim: &BoundedVec<u8, T::MaxClaimLenth>,
+        ) -> Result<T::AccountId, DispatchError> {
+            let owner = Proofs::<T, I>::get(claim)
+                .ok_or(Error::<T, I>::ClaimNotExist)?
+                .owner;
+            ensure!(
+                *who == owner || Self::is_operator(&owner, who, claim),
+                Error::<T, I>::NotClaimOwner
+            );
+            Self::ensure_not_frozen(claim)?;
+            Ok(owner)
+        }
+
+        /// Check that `who` owns `claim`, for actions that operators may not take.
+        fn ensure_owner(
+            who: &T::AccountId,
+            claim: &BoundedVec<u8, T::MaxClaimLenth>,
+        ) -> Result<T::AccountId, DispatchError> {
+            let owner = Proofs::<T, I>::get(claim)
+                .ok_or(Error::<T, I>::ClaimNotExist)?
+                .owner;
+            if *who != owner {
+                return Err(if Self::is_operator(&owner, who, claim) {
+                    Error::<T, I>::NotApproved
+                } else {
+                    Error::<T, I>::NotClaimOwner
+                }
+                .into());
+            }
+            Ok(owner)
+        }
+
+        fn ensure_not_frozen(claim: &BoundedVec<u8, T::MaxClaimLenth>) -> DispatchResult {
+            ensure!(
+                !Frozen::<T, I>::contains_key(claim),
+                Error::<T, I>::ClaimFrozen
+            );
+            Ok(())
+        }
+
+        fn ensure_hash_not_frozen(hash: &T::Hash) -> DispatchResult {
+            ensure!(
+                !HashFrozen::<T, I>::contains_key(hash),
+                Error::<T, I>::ClaimFrozen
+            );
+            Ok(())
+        }
+
+        /// Hash arbitrary content into the digest accepted by `create_hash_claim`.
+        ///
+        /// Clients can compute the same digest off-chain to prove the existence of documents of
+        /// any size without publishing them.
+        pub fn hash_of(content: &[u8]) -> T::Hash {
+            T::Hashing::hash(content)
+        }
+
+        /// Check that `owner` may take one more claim, and that the registry has room for a new
+        /// one if `new` is set.
+        fn ensure_quota(owner: &T::AccountId, new: bool) -> DispatchResult {
+            ensure!(
+                ClaimCount::<T, I>::get(owner) < T::MaxClaimsPerAccount::get(),
+                Error::<T, I>::TooManyClaims
+            );
+            if let Some(max) = T::MaxTotalClaims::get().filter(|_| new) {
+                let total = Proofs::<T, I>::count().saturating_add(HashProofs::<T, I>::count());
+                ensure!(total < max, Error::<T, I>::TooManyClaims);
+            }
+            Ok(())
+        }
+
+        /// Apply `f` to each claim of a batch.
+        ///
+        /// Unless `atomic` is set, the changes of a failing claim are rolled back and the batch
+        /// carries on.
+        fn do_batch(
+            claims: BoundedVec<BoundedVec<u8, T::MaxClaimLenth>, T::MaxBatchSize>,
+            atomic: bool,
+            mut f: impl FnMut(BoundedVec<u8, T::MaxClaimLenth>) -> DispatchResult,
+        ) -> DispatchResult {
+            let total = claims.len() as u32;
+            let mut failed = 0u32;
+            for claim in claims {
+                if atomic {
+                    f(claim)?;
+                } else if let Err(error) = with_storage_layer(|| f(claim.clone())) {
+                    failed.saturating_inc();
+                    Self::deposit_event(Event::BatchItemFailed(claim, error));
+                }
+            }
+
+            Self::deposit_event(Event::BatchCompleted(total.saturating_sub(failed), failed));
+            Ok(())
+        }
+
+        /// Revoke `claim` on behalf of `who`, who must own it or be an approved operator.
+        fn do_revoke(
+            who: &T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLenth>,
+        ) -> DispatchResult {
+            let owner = Self::ensure_approved(who, &claim)?;
+            Self::do_remove(&owner, &claim, ClaimAction::Revoked, false)?;
+
+            Self::deposit_event(Event::ClaimRevoked(owner, claim));
+            Ok(())
+        }
+
+        /// The payload `owner` signs to authorize `create_claim_signed` for `claim` on this
+        /// instance, until `deadline`.
+        pub fn signed_claim_payload(
+            claim: BoundedVec<u8, T::MaxClaimLenth>,
+            owner: T::AccountId,
+            deadline: BlockNumberFor<T>,
+        ) -> SignedClaimPayloadOf<T, I> {
+            SignedClaimPayload {
+                nonce: SignerNonces::<T, I>::get(&owner),
+                claim,
+                owner,
+                deadline,
+                pallet_index: Self::index() as u32,
+                genesis_hash: frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero()),
+            }
+        }
+
+        /// Register `claim` for `owner`, holding its deposit from `depositor`.
+        fn do_create(
+            depositor: &T::AccountId,
+            owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLenth>,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            ensure!(
+                !Proofs::<T, I>::contains_key(&claim),
+                Error::<T, I>::ProofAlreadyExist
+            );
+            Self::ensure_quota(&owner, true)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(at) = expires_at {
+                ensure!(at > now, Error::<T, I>::InvalidExpiry);
+                ExpiryQueue::<T, I>::try_mutate(at, |queue| queue.try_push(claim.clone()))
+                    .map_err(|_| Error::<T, I>::ExpiryQueueFull)?;
+                ClaimExpiry::<T, I>::insert(&claim, at);
+            }
+            let deposit = Self::hold_deposit(depositor, claim.len() as u32)?;
+            Deposits::<T, I>::insert(&claim, (depositor.clone(), deposit));
+            let timestamp = Self::now();
+            Proofs::<T, I>::insert(
+                &claim,
+                ClaimInfo {
+                    owner: owner.clone(),
+                    block_number: now,
+                    timestamp,
+                },
+            );
+            ClaimsOf::<T, I>::insert(&owner, &claim, ());
+            ClaimCount::<T, I>::mutate(&owner, |count| count.saturating_inc());
+            History::<T, I>::mutate(&claim, |history| {
+                Self::record(history, owner.clone(), ClaimAction::Created)
+            });
+            Self::mint_nft(&owner, &claim)?;
+
+            Self::deposit_event(Event::ClaimCreated(owner, claim, timestamp));
+            Ok(())
+        }
+
+        /// Compute the Merkle root reached from `leaf` by hashing it with each sibling in `proof`.
+        ///
+        /// The tree hashes a leaf as `hash(0x00 ++ leaf)` and a pair of nodes as
+        /// `hash(0x01 ++ lower ++ higher)`, so an inner node can never pass as a leaf. Each pair is
+        /// sorted before hashing, so proofs do not need to encode the position of the siblings.
+        pub fn merkle_root(leaf: T::Hash, proof: &[T::Hash]) -> T::Hash {
+            let leaf = T::Hashing::hash_of(&(MERKLE_LEAF_PREFIX, leaf));
+            proof.iter().fold(leaf, |node, sibling| {
+                if node <= *sibling {
+                    T::Hashing::hash_of(&(MERKLE_NODE_PREFIX, node, *sibling))
+                } else {
+                    T::Hashing::hash_of(&(MERKLE_NODE_PREFIX, *sibling, node))
+                }
+            })
+        }
+
+        /// Whether `leaf` is part of the registered Merkle claim `root`.
+        pub fn is_included(root: T::Hash, leaf: T::Hash, proof: &[T::Hash]) -> bool {
+            MerkleRoots::<T, I>::contains_key(&root) && Self::merkle_root(leaf, proof) == root
+        }
+
+        /// Register the hash claim `hash` for `owner`, holding its deposit.
+        fn do_create_hash(owner: T::AccountId, hash: T::Hash) -> DispatchResult {
+            ensure!(
+                !HashProofs::<T, I>::contains_key(&hash),
+                Error::<T, I>::ProofAlreadyExist
+            );
+            Self::ensure_quota(&owner, true)?;
+
+            let deposit = Self::hold_deposit(&owner, hash.as_ref().len() as u32)?;
+            let timestamp = Self::now();
+            HashDeposits::<T, I>::insert(&hash, deposit);
+            HashProofs::<T, I>::insert(
+                &hash,
+                ClaimInfo {
+                    owner: owner.clone(),
+                    block_number: frame_system::Pallet::<T>::block_number(),
+                    timestamp,
+                },
+            );
+            ClaimCount::<T, I>::mutate(&owner, |count| count.saturating_inc());
+            HashClaimsOf::<T, I>::insert(&owner, &hash, ());
+            HashHistory::<T, I>::mutate(&hash, |history| {
+                Self::record(history, owner.clone(), ClaimAction::Created)
+            });
+
+            Self::deposit_event(Event::HashClaimCreated(owner, hash, timestamp));
+            Ok(())
+        }
+
+        /// Remove the hash claim `hash` and everything attached to it, releasing the deposit.
+        fn do_remove_hash(owner: &T::AccountId, hash: &T::Hash) -> DispatchResult {
+            HashProofs::<T, I>::remove(hash);
+            ClaimCount::<T, I>::mutate(owner, |count| count.saturating_dec());
+            HashClaimsOf::<T, I>::remove(owner, hash);
+            HashMetadata::<T, I>::remove(hash);
+            MerkleRoots::<T, I>::remove(hash);
+            HashFrozen::<T, I>::remove(hash);
+            if let Some(deposit) = HashDeposits::<T, I>::take(hash) {
+                Self::release_deposit(owner, deposit)?;
+            }
+            HashHistory::<T, I>::mutate(hash, |history| {
+                Self::record(history, owner.clone(), ClaimAction::Revoked)
+            });
+            Ok(())
+        }
+
+        /// Move the hash claim `hash` from `from` to `to`, along with its deposit.
+        ///
+        /// The caller is responsible for checking that `from` owns the claim.
+        fn do_transfer_hash(from: T::AccountId, to: T::AccountId, hash: T::Hash) -> DispatchResult {
+            let mut info = HashProofs::<T, I>::get(&hash).ok_or(Error::<T, I>::ClaimNotExist)?;
+            if from != to {
+                Self::ensure_quota(&to, false)?;
+                ClaimCount::<T, I>::mutate(&from, |count| count.saturating_dec());
+                ClaimCount::<T, I>::mutate(&to, |count| count.saturating_inc());
+            }
+            if let Some(deposit) = HashDeposits::<T, I>::get(&hash) {
+                Self::transfer_deposit(&from, &to, deposit)?;
+            }
+            info.owner = to.clone();
+            HashProofs::<T, I>::insert(&hash, info);
+            HashClaimsOf::<T, I>::remove(&from, &hash);
+            HashClaimsOf::<T, I>::insert(&to, &hash, ());
+            HashHistory::<T, I>::mutate(&hash, |history| {
+                Self::record(history, to.clone(), ClaimAction::Transferred)
+            });
+
+            Self::deposit_event(Event::HashClaimTransfered(from, to, hash, Self::now()));
+            Ok(())
+        }
+
+        /// Remove `claim` and everything attached to it, releasing the deposit.
+        ///
+        /// A `forced` removal goes ahead even if the NFT of the claim cannot be burnt.
+        fn do_remove(
+            owner: &T::AccountId,
+            claim: &BoundedVec<u8, T::MaxClaimLenth>,
+            action: ClaimAction,
+            forced: bool,
+        ) -> DispatchResult {
+            Proofs::<T, I>::remove(claim);
+            ClaimsOf::<T, I>::remove(owner, claim);
+            ClaimCount::<T, I>::mutate(owner, |count| count.saturating_dec());
+            PendingTransfers::<T, I>::remove(claim);
+            ClaimOperators::<T, I>::remove(claim);
+            Frozen::<T, I>::remove(claim);
+            Metadata::<T, I>::remove(claim);
+            if let Some(at) = ClaimExpiry::<T, I>::take(claim) {
+                ExpiryQueue::<T, I>::mutate_exists(at, |maybe_queue| {
+                    if let Some(queue) = maybe_queue.as_mut() {
+                        queue.retain(|c| c != claim);
+                    }
+                    if maybe_queue.as_ref().map_or(false, |queue| queue.is_empty()) {
+                        *maybe_queue = None;
+                    }
+                });
+            }
+            if let Some((depositor, deposit)) = Deposits::<T, I>::take(claim) {
+                Self::release_deposit(&depositor, deposit)?;
+            }
+            Self::sync_nft(claim, forced, || Self::burn_nft(claim))?;
+            History::<T, I>::mutate(claim, |history| {
+                Self::record(history, owner.clone(), action)
+            });
+            Ok(())
+        }
+
+        /// Mint an NFT mirroring `claim` to `owner`, if an NFT collection is set.
+        fn mint_nft(
+            owner: &T::AccountId,
+            claim: &BoundedVec<u8, T::MaxClaimLenth>,
+        ) -> DispatchResult {
+            let Some(collection) = NftCollection::<T, I>::get() else {
+                return Ok(());
+            };
+            let item = NextNftId::<T, I>::get().unwrap_or_else(T::FirstNftId::get);
+            let next = item.increment().ok_or(Error::<T, I>::NftIdsExhausted)?;
+
+            T::Nfts::mint_into(&collection, &item, owner, &T::ClaimNftConfig::get(), true)?;
+            // The NFT only moves with its claim.
+            T::Nfts::disable_transfer(&collection, &item)?;
+            NextNftId::<T, I>::put(next);
+            ClaimNfts::<T, I>::insert(claim, (collection, item));
+
+            Self::deposit_event(Event::ClaimNftMinted(claim.clone(), collection, item));
+            Ok(())
+        }
+
+        /// Burn the NFT mirroring `claim`, if any.
+        fn burn_nft(claim: &BoundedVec<u8, T::MaxClaimLenth>) -> DispatchResult {
+            if let Some((collection, item)) = ClaimNfts::<T, I>::take(claim) {
+                // Its holder may already have burnt the NFT.
+                if T::Nfts::owner(&collection, &item).is_some() {
+                    T::Nfts::burn(&collection, &item, None)?;
+                }
+            }
+            Ok(())
+        }
+
+        /// Hand the NFT mirroring `claim` to `to`, if any.
+        fn transfer_nft(
+            claim: &BoundedVec<u8, T::MaxClaimLenth>,
+            to: &T::AccountId,
+        ) -> DispatchResult {
+            if let Some((collection, item)) = ClaimNfts::<T, I>::get(claim) {
+                // The NFT cannot be traded on its own, so it is held by the previous owner
+                // unless it was burnt.
+                if T::Nfts::owner(&collection, &item).is_some() {
+                    T::Nfts::enable_transfer(&collection, &item)?;
+                    T::Nfts::transfer(&collection, &item, to)?;
+                    T::Nfts::disable_transfer(&collection, &item)?;
+                }
+            }
+            Ok(())
+        }
+
+        /// Make the NFT of `claim` follow a change to the claim with `sync`.
+        ///
+        /// A `forced` change must not be held up by the NFT, so if it cannot follow, it is
+        /// detached from the claim instead of failing the change.
+        fn sync_nft(
+            claim: &BoundedVec<u8, T::MaxClaimLenth>,
+            forced: bool,
+            sync: impl FnOnce() -> DispatchResult,
+        ) -> DispatchResult {
+            if !forced {
+                return sync();
+            }
+            if let Err(error) = with_storage_layer(sync) {
+                ClaimNfts::<T, I>::remove(claim);
+                Self::deposit_event(Event::ClaimNftDetached(claim.clone(), error));
+            }
+            Ok(())
+        }
+
+        /// Move `claim` from `from` to `to`, along with its deposit unless it was sponsored.
+        ///
+        /// The caller is responsible for checking that `from` owns the claim. Any operator
+        /// approved for the claim alone loses the approval. A `forced` transfer goes ahead
+        /// even if the NFT of the claim cannot follow it.
+        fn do_transfer(
+            from: T::AccountId,
+            to: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLenth>,
+            forced: bool,
+        ) -> DispatchResult {
+            let mut info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExist)?;
+            if from != to {
+                Self::ensure_quota(&to, false)?;
+                ClaimCount::<T, I>::mutate(&from, |count| count.saturating_dec());
+                ClaimCount::<T, I>::mutate(&to, |count| count.saturating_inc());
+            }
+            if let Some((depositor, deposit)) = Deposits::<T, I>::get(&claim) {
+                // A relayer's deposit is theirs, so it stays with them on transfer.
+                if depositor == from && from != to {
+                    Self::transfer_deposit(&from, &to, deposit)?;
+                    Deposits::<T, I>::insert(&claim, (to.clone(), deposit));
+                }
+            }
+            info.owner = to.clone();
+            Proofs::<T, I>::insert(&claim, info);
+            ClaimsOf::<T, I>::remove(&from, &claim);
+            ClaimsOf::<T, I>::insert(&to, &claim, ());
+            PendingTransfers::<T, I>::remove(&claim);
+            ClaimOperators::<T, I>::remove(&claim);
+            Self::sync_nft(&claim, forced, || Self::transfer_nft(&claim, &to))?;
+            History::<T, I>::mutate(&claim, |history| {
+                Self::record(history, to.clone(), ClaimAction::Transferred)
+            });
+
+            Self::deposit_event(Event::ClaimTransfered(from, to, claim, Self::now()));
+            Ok(())
+        }
+
+        /// The current time in milliseconds since the Unix epoch.
+        fn now() -> u64 {
+            T::UnixTime::now().as_millis().saturated_into()
+        }
+
+        /// Append a provenance record, dropping the oldest one if the history is full.
+        fn record(
+            history: &mut BoundedVec<ProvenanceRecordOf<T>, T::MaxHistoryLength>,
+            owner: T::AccountId,
+            action: ClaimAction,
+        ) {
+            let record = ProvenanceRecord {
+                owner,
+                block_number: frame_system::Pallet::<T>::block_number(),
+                action,
+            };
+            if let Err(record) = history.try_push(record) {
+                if !history.is_empty() {
+                    history.remove(0);
+                    let _ = history.try_push(record);
+                }
+            }
+        }
+
+        fn hold_deposit(who: &T::AccountId, len: u32) -> Result<BalanceOf<T, I>, DispatchError> {
+            let deposit = Self::claim_deposit(len);
+            T::Currency::hold(&HoldReason::<I>::ClaimDeposit.into(), who, deposit)
+                .map_err(|_| Error::<T, I>::InsufficientBalance)?;
+
+            Self::deposit_event(Event::DepositHeld(who.clone(), deposit));
+            Ok(deposit)
+        }
+
+        fn release_deposit(who: &T::AccountId, deposit: BalanceOf<T, I>) -> DispatchResult {
+            let released = T::Currency::release(
+                &HoldReason::<I>::ClaimDeposit.into(),
+                who,
+                deposit,
+                Precision::BestEffort,
+            )?;
+
+            Self::deposit_event(Event::DepositReleased(who.clone(), released));
+            Ok(())
+        }
+
+        fn transfer_deposit(
+            from: &T::AccountId,
+            to: &T::AccountId,
+            deposit: BalanceOf<T, I>,
+        ) -> DispatchResult {
+            T::Currency::transfer_on_hold(
+                &HoldReason::<I>::ClaimDeposit.into(),
+                from,
+                to,
+                deposit,
+                Precision::Exact,
+                Restriction::OnHold,
+                Fortitude::Polite,
+            )
+            .map_err(|_| Error::<T, I>::CannotTransferDeposit)?;
+
+            Self::deposit_event(Event::DepositTransfered(from.clone(), to.clone(), deposit));
+            Ok(())
+        }
     }
 }
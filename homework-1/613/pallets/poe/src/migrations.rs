@@ -0,0 +1,291 @@
+//! Storage migrations for the PoE pallet.
+
+use super::*;
+
+/// Move `Proofs` from `(owner, block_number)` tuples to [`ClaimInfo`].
+pub mod v1 {
+    use super::*;
+    use frame_support::{storage_alias, traits::OnRuntimeUpgrade};
+
+    /// [`ClaimInfo`] as stored in versions 1 and 2, before claims were timestamped.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+    pub struct ClaimInfo<AccountId, BlockNumber> {
+        pub owner: AccountId,
+        pub block_number: BlockNumber,
+    }
+
+    #[storage_alias]
+    pub type Proofs<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config<I>>::MaxClaimLenth>,
+        ClaimInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+    >;
+
+    #[cfg(feature = "try-runtime")]
+    use sp_runtime::TryRuntimeError;
+
+    mod v0 {
+        use super::*;
+
+        #[storage_alias]
+        pub type Proofs<T: Config<I>, I: 'static> = StorageMap<
+            Pallet<T, I>,
+            Blake2_128Concat,
+            BoundedVec<u8, <T as Config<I>>::MaxClaimLenth>,
+            (<T as frame_system::Config>::AccountId, BlockNumberFor<T>),
+        >;
+    }
+
+    /// Translate every claim to [`ClaimInfo`] without checking the storage version.
+    ///
+    /// Use [`MigrateV0ToV1`] instead.
+    pub struct VersionUncheckedMigrateV0ToV1<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for VersionUncheckedMigrateV0ToV1<T, I> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((v0::Proofs::<T, I>::iter().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Proofs::<T, I>::translate::<(T::AccountId, BlockNumberFor<T>), _>(
+                |_, (owner, block_number)| {
+                    translated.saturating_inc();
+                    Some(ClaimInfo {
+                        owner,
+                        block_number,
+                    })
+                },
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let count = u32::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("invalid pre-upgrade state"))?;
+            // `iter` skips entries that fail to decode, so a matching count means every claim
+            // was translated.
+            ensure!(
+                Proofs::<T, I>::iter().count() as u32 == count,
+                TryRuntimeError::Other("claims were lost in the migration")
+            );
+            Ok(())
+        }
+    }
+
+    /// Migrate the pallet from storage version 0 to 1, doing nothing at any other version.
+    pub type MigrateV0ToV1<T, I = ()> = frame_support::migrations::VersionedMigration<
+        0,
+        1,
+        VersionUncheckedMigrateV0ToV1<T, I>,
+        Pallet<T, I>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Count the claims of the registry and of each account, now that `Proofs` is a
+/// `CountedStorageMap`.
+pub mod v2 {
+    use super::*;
+    use frame_support::{storage_alias, traits::OnRuntimeUpgrade};
+
+    #[cfg(feature = "try-runtime")]
+    use sp_runtime::TryRuntimeError;
+
+    /// The counter of the `Proofs` map, which `initialize_counter` can't fill in while the
+    /// claims still have their version 1 layout.
+    #[storage_alias]
+    type CounterForProofs<T: Config<I>, I: 'static> = StorageValue<Pallet<T, I>, u32, ValueQuery>;
+
+    /// Initialize the claim counters without checking the storage version.
+    ///
+    /// Use [`MigrateV1ToV2`] instead.
+    pub struct VersionUncheckedMigrateV1ToV2<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for VersionUncheckedMigrateV1ToV2<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut total = 0u32;
+            for (_, info) in v1::Proofs::<T, I>::iter() {
+                total.saturating_inc();
+                ClaimCount::<T, I>::mutate(&info.owner, |count| count.saturating_inc());
+            }
+            CounterForProofs::<T, I>::put(total);
+
+            let total = total as u64;
+            T::DbWeight::get().reads_writes(total.saturating_mul(2), total.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let total = v1::Proofs::<T, I>::iter().count() as u32;
+            ensure!(
+                CounterForProofs::<T, I>::get() == total,
+                TryRuntimeError::Other("claim counter does not match the claims")
+            );
+            ensure!(
+                ClaimCount::<T, I>::iter_values().sum::<u32>() == total,
+                TryRuntimeError::Other("account claim counts do not add up")
+            );
+            Ok(())
+        }
+    }
+
+    /// Migrate the pallet from storage version 1 to 2, doing nothing at any other version.
+    pub type MigrateV1ToV2<T, I = ()> = frame_support::migrations::VersionedMigration<
+        1,
+        2,
+        VersionUncheckedMigrateV1ToV2<T, I>,
+        Pallet<T, I>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Record a creation timestamp in [`ClaimInfo`].
+pub mod v3 {
+    use super::*;
+    use frame_support::traits::OnRuntimeUpgrade;
+
+    #[cfg(feature = "try-runtime")]
+    use sp_runtime::TryRuntimeError;
+
+    /// Translate every claim to the timestamped [`ClaimInfo`] without checking the storage
+    /// version.
+    ///
+    /// The time existing claims were created at is unknown, so their timestamp is 0.
+    ///
+    /// Use [`MigrateV2ToV3`] instead.
+    pub struct VersionUncheckedMigrateV2ToV3<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for VersionUncheckedMigrateV2ToV3<T, I> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((v1::Proofs::<T, I>::iter().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Proofs::<T, I>::translate::<v1::ClaimInfo<T::AccountId, BlockNumberFor<T>>, _>(
+                |_, old| {
+                    translated.saturating_inc();
+                    Some(ClaimInfo {
+                        owner: old.owner,
+                        block_number: old.block_number,
+                        timestamp: 0,
+                    })
+                },
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let count = u32::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("invalid pre-upgrade state"))?;
+            ensure!(
+                Proofs::<T, I>::iter().count() as u32 == count,
+                TryRuntimeError::Other("claims were lost in the migration")
+            );
+            ensure!(
+                Proofs::<T, I>::count() == count,
+                TryRuntimeError::Other("claim counter does not match the claims")
+            );
+            Ok(())
+        }
+    }
+
+    /// Migrate the pallet from storage version 2 to 3, doing nothing at any other version.
+    pub type MigrateV2ToV3<T, I = ()> = frame_support::migrations::VersionedMigration<
+        2,
+        3,
+        VersionUncheckedMigrateV2ToV3<T, I>,
+        Pallet<T, I>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Move `HashProofs` from `(owner, block_number)` tuples to [`ClaimInfo`], and count hash
+/// claims towards the claim quotas.
+pub mod v4 {
+    use super::*;
+    use frame_support::{storage_alias, traits::OnRuntimeUpgrade};
+
+    #[cfg(feature = "try-runtime")]
+    use sp_runtime::TryRuntimeError;
+
+    mod v3 {
+        use super::*;
+
+        #[storage_alias]
+        pub type HashProofs<T: Config<I>, I: 'static> = StorageMap<
+            Pallet<T, I>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::Hash,
+            (<T as frame_system::Config>::AccountId, BlockNumberFor<T>),
+        >;
+    }
+
+    /// Translate every hash claim to [`ClaimInfo`] and count it in `ClaimCount` and the
+    /// `HashProofs` counter, without checking the storage version.
+    ///
+    /// The time existing hash claims were created at is unknown, so their timestamp is 0.
+    ///
+    /// Use [`MigrateV3ToV4`] instead.
+    pub struct VersionUncheckedMigrateV3ToV4<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for VersionUncheckedMigrateV3ToV4<T, I> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((v3::HashProofs::<T, I>::iter().count() as u32).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            HashProofs::<T, I>::translate::<(T::AccountId, BlockNumberFor<T>), _>(
+                |_, (owner, block_number)| {
+                    translated.saturating_inc();
+                    ClaimCount::<T, I>::mutate(&owner, |count| count.saturating_inc());
+                    Some(ClaimInfo {
+                        owner,
+                        block_number,
+                        timestamp: 0,
+                    })
+                },
+            );
+            HashProofs::<T, I>::initialize_counter();
+
+            T::DbWeight::get().reads_writes(
+                translated.saturating_mul(3),
+                translated.saturating_mul(2).saturating_add(1),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let count = u32::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("invalid pre-upgrade state"))?;
+            ensure!(
+                HashProofs::<T, I>::iter().count() as u32 == count,
+                TryRuntimeError::Other("hash claims were lost in the migration")
+            );
+            ensure!(
+                HashProofs::<T, I>::count() == count,
+                TryRuntimeError::Other("hash claim counter does not match the hash claims")
+            );
+            Ok(())
+        }
+    }
+
+    /// Migrate the pallet from storage version 3 to 4, doing nothing at any other version.
+    pub type MigrateV3ToV4<T, I = ()> = frame_support::migrations::VersionedMigration<
+        3,
+        4,
+        VersionUncheckedMigrateV3ToV4<T, I>,
+        Pallet<T, I>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,7 +1,11 @@
 use crate as pallet_poe;
-use frame_support::traits::{ConstU16, ConstU32, ConstU64};
+use frame_support::{
+    parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstBool, ConstU16, ConstU32, ConstU64},
+};
 use sp_core::H256;
 use sp_runtime::{
+    testing::{TestSignature, UintAuthorityId},
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
 };
@@ -13,7 +17,11 @@ frame_support::construct_runtime!(
     pub enum Test
     {
         System: frame_system,
+        Balances: pallet_balances,
+        Timestamp: pallet_timestamp,
+        Nfts: pallet_nfts,
         PoeModule: pallet_poe,
+        PoeArtifacts: pallet_poe::<Instance1>,
     }
 );
 
@@ -35,7 +43,7 @@ impl frame_system::Config for Test {
     type BlockHashCount = ConstU64<250>;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -49,15 +57,178 @@ impl frame_system::Config for Test {
     type PostTransactions = ();
 }
 
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<5>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub storage NftFeatures: pallet_nfts::PalletFeatures = pallet_nfts::PalletFeatures::all_enabled();
+    pub ClaimNftConfig: pallet_nfts::ItemConfig = pallet_nfts::ItemConfig {
+        settings: pallet_nfts::ItemSettings::all_enabled(),
+    };
+}
+
+impl pallet_nfts::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+    type ForceOrigin = frame_system::EnsureRoot<u64>;
+    type Locker = ();
+    type CollectionDeposit = ConstU64<2>;
+    type ItemDeposit = ConstU64<1>;
+    type MetadataDepositBase = ConstU64<1>;
+    type AttributeDepositBase = ConstU64<1>;
+    type DepositPerByte = ConstU64<1>;
+    type StringLimit = ConstU32<50>;
+    type KeyLimit = ConstU32<50>;
+    type ValueLimit = ConstU32<50>;
+    type ApprovalsLimit = ConstU32<10>;
+    type ItemAttributesApprovalsLimit = ConstU32<2>;
+    type MaxTips = ConstU32<10>;
+    type MaxDeadlineDuration = ConstU64<10000>;
+    type MaxAttributesPerCall = ConstU32<2>;
+    type Features = NftFeatures;
+    type OffchainSignature = TestSignature;
+    type OffchainPublic = UintAuthorityId;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+}
+
+parameter_types! {
+    pub static AllowDirectTransfer: bool = true;
+    pub static MaxClaimsPerAccount: u32 = 10;
+    pub static MaxTotalClaims: Option<u32> = None;
+}
+
 impl pallet_poe::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
     type MaxClaimLenth = ConstU32<10>;
+    type UnixTime = Timestamp;
+    type ClaimDepositBase = ConstU64<10>;
+    type ClaimDepositPerByte = ConstU64<1>;
+    type AllowDirectTransfer = AllowDirectTransfer;
+    type MaxExpiriesPerBlock = ConstU32<2>;
+    type MaxMetadataLength = ConstU32<16>;
+    type MaxHistoryLength = ConstU32<3>;
+    type MaxMerkleProofLength = ConstU32<8>;
+    type OffchainSignature = TestSignature;
+    type OffchainPublic = UintAuthorityId;
+    type ForceOrigin = frame_system::EnsureRoot<u64>;
+    type MaxReasonLength = ConstU32<16>;
+    type MaxClaimsPerAccount = MaxClaimsPerAccount;
+    type MaxTotalClaims = MaxTotalClaims;
+    type MaxBatchSize = ConstU32<5>;
+    type MaxContentLength = ConstU32<64>;
+    type NftCollectionId = u32;
+    type NftId = u32;
+    type FirstNftId = ConstU32<0>;
+    type NftItemConfig = pallet_nfts::ItemConfig;
+    type ClaimNftConfig = ClaimNftConfig;
+    type Nfts = Nfts;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = PoeBenchmarkHelper;
+}
+
+// A second registry with longer claims and a flat deposit.
+impl pallet_poe::Config<pallet_poe::Instance1> for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type MaxClaimLenth = ConstU32<20>;
+    type UnixTime = Timestamp;
+    type ClaimDepositBase = ConstU64<5>;
+    type ClaimDepositPerByte = ConstU64<0>;
+    type AllowDirectTransfer = ConstBool<true>;
+    type MaxExpiriesPerBlock = ConstU32<2>;
+    type MaxMetadataLength = ConstU32<16>;
+    type MaxHistoryLength = ConstU32<3>;
+    type MaxMerkleProofLength = ConstU32<8>;
+    type OffchainSignature = TestSignature;
+    type OffchainPublic = UintAuthorityId;
+    type ForceOrigin = frame_system::EnsureRoot<u64>;
+    type MaxReasonLength = ConstU32<16>;
+    type MaxClaimsPerAccount = ConstU32<10>;
+    type MaxTotalClaims = ();
+    type MaxBatchSize = ConstU32<5>;
+    type MaxContentLength = ConstU32<64>;
+    type NftCollectionId = u32;
+    type NftId = u32;
+    type FirstNftId = ConstU32<1_000>;
+    type NftItemConfig = pallet_nfts::ItemConfig;
+    type ClaimNftConfig = ClaimNftConfig;
+    type Nfts = Nfts;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = PoeBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct PoeBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_poe::BenchmarkHelper<UintAuthorityId, u64, TestSignature, u32> for PoeBenchmarkHelper {
+    fn signer() -> (UintAuthorityId, u64) {
+        (UintAuthorityId(42), 42)
+    }
+
+    fn sign(signer: &UintAuthorityId, message: &[u8]) -> TestSignature {
+        TestSignature(signer.0, message.to_vec())
+    }
+
+    fn nft_collection() -> Option<u32> {
+        use frame_support::traits::{fungible::Mutate, tokens::nonfungibles_v2::Create};
+
+        let owner = 99;
+        Balances::set_balance(&owner, 1_000);
+        let config = pallet_nfts::CollectionConfig {
+            settings: pallet_nfts::CollectionSettings::all_enabled(),
+            max_supply: None,
+            mint_settings: pallet_nfts::MintSettings::default(),
+        };
+        <Nfts as Create<u64, pallet_nfts::CollectionConfigFor<Test>>>::create_collection(
+            &owner, &owner, &config,
+        )
+        .ok()
+    }
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default()
+    let mut t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
-        .unwrap()
-        .into()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 100), (3, 100), (4, 5)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
 }
@@ -1,20 +1,40 @@
 use super::*;
 use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{
+        fungible::{Inspect, InspectHold},
+        ConstU32, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion,
+    },
+    BoundedVec,
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::TestSignature,
+    traits::{BlakeTwo256, Hash},
+    BuildStorage,
+};
 
 #[test]
 fn create_claim_works() {
     new_test_ext().execute_with(|| {
+        Timestamp::set_timestamp(1_000);
         let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
         assert_ok!(PoeModule::create_claim(
             RuntimeOrigin::signed(1),
-            claim.clone()
+            claim.clone(),
+            None
         ));
 
         assert_eq!(
             Proofs::<Test>::get(&claim),
-            Some((1, frame_system::Pallet::<Test>::block_number()))
+            Some(ClaimInfo {
+                owner: 1,
+                block_number: frame_system::Pallet::<Test>::block_number(),
+                timestamp: 1_000,
+            })
         );
+        System::assert_last_event(Event::<Test>::ClaimCreated(1, claim, 1_000).into());
         assert_eq!(<<Test as Config>::MaxClaimLenth as Get<u32>>::get(), 10);
     })
 }
@@ -23,10 +43,10 @@ fn create_claim_works() {
 fn create_claim_failed_when_claim_already_exist() {
     new_test_ext().execute_with(|| {
         let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
-        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
 
         assert_noop!(
-            PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()),
+            PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None),
             Error::<Test>::ProofAlreadyExist
         );
     })
@@ -36,7 +56,7 @@ fn create_claim_failed_when_claim_already_exist() {
 fn revoke_claim_works() {
     new_test_ext().execute_with(|| {
         let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
-        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
 
         assert_ok!(PoeModule::revoke_claim(
             RuntimeOrigin::signed(1),
@@ -49,7 +69,7 @@ fn revoke_claim_works() {
 fn revoke_claim_failed_when_claim_is_not_exist() {
     new_test_ext().execute_with(|| {
         let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
-        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
 
         let claim_other = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
 
@@ -64,7 +84,7 @@ fn revoke_claim_failed_when_claim_is_not_exist() {
 fn revoke_claim_failed_with_wrong_owner() {
     new_test_ext().execute_with(|| {
         let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
-        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
 
         assert_noop!(
             PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim.clone()),
@@ -77,7 +97,7 @@ fn revoke_claim_failed_with_wrong_owner() {
 fn transfer_claim_works() {
     new_test_ext().execute_with(|| {
         let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
-        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
 
         assert_ok!(PoeModule::transfer_claim(
             RuntimeOrigin::signed(1),
@@ -89,8 +109,57 @@ fn transfer_claim_works() {
             BoundedVec::<u8, <Test as Config>::MaxClaimLenth>::try_from(claim.clone()).unwrap();
         assert_eq!(
             Proofs::<Test>::get(&bounded_claim),
-            Some((2, frame_system::Pallet::<Test>::block_number()))
+            Some(ClaimInfo {
+                owner: 2,
+                block_number: frame_system::Pallet::<Test>::block_number(),
+                timestamp: 0,
+            })
+        );
+    })
+}
+
+#[test]
+fn transfer_claim_keeps_creation_time_and_reports_transfer_time() {
+    new_test_ext().execute_with(|| {
+        Timestamp::set_timestamp(1_000);
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+
+        Timestamp::set_timestamp(2_000);
+        assert_ok!(PoeModule::transfer_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            2
+        ));
+
+        assert_eq!(
+            Proofs::<Test>::get(&claim).map(|info| info.timestamp),
+            Some(1_000)
+        );
+        System::assert_last_event(Event::<Test>::ClaimTransfered(1, 2, claim, 2_000).into());
+    })
+}
+
+#[test]
+fn hash_claim_records_creation_time_and_reports_transfer_time() {
+    new_test_ext().execute_with(|| {
+        Timestamp::set_timestamp(1_000);
+        let hash = PoeModule::hash_of(b"document");
+        assert_ok!(PoeModule::create_hash_claim(RuntimeOrigin::signed(1), hash));
+        System::assert_last_event(Event::<Test>::HashClaimCreated(1, hash, 1_000).into());
+
+        Timestamp::set_timestamp(2_000);
+        assert_ok!(PoeModule::transfer_hash_claim(
+            RuntimeOrigin::signed(1),
+            hash,
+            2
+        ));
+
+        assert_eq!(
+            HashProofs::<Test>::get(hash).map(|info| info.timestamp),
+            Some(1_000)
         );
+        System::assert_last_event(Event::<Test>::HashClaimTransfered(1, 2, hash, 2_000).into());
     })
 }
 
@@ -98,7 +167,7 @@ fn transfer_claim_works() {
 fn transfer_claim_failed_when_claim_is_not_exist() {
     new_test_ext().execute_with(|| {
         let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
-        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
 
         let claim_other = BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
 
@@ -113,7 +182,7 @@ fn transfer_claim_failed_when_claim_is_not_exist() {
 fn transfer_claim_failed_with_wrong_owner() {
     new_test_ext().execute_with(|| {
         let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
-        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
 
         assert_noop!(
             PoeModule::transfer_claim(RuntimeOrigin::signed(2), claim.clone(), 3),
@@ -121,3 +190,1988 @@ fn transfer_claim_failed_with_wrong_owner() {
         );
     })
 }
+
+#[test]
+fn create_claim_holds_deposit() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        assert_ok!(PoeModule::create_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            None
+        ));
+
+        // 10 base + 3 bytes * 1 per byte
+        assert_eq!(Deposits::<Test>::get(&claim), Some((1, 13)));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::<()>::ClaimDeposit.into(), &1),
+            13
+        );
+        assert_eq!(Balances::free_balance(1), 87);
+        System::assert_has_event(Event::<Test>::DepositHeld(1, 13).into());
+    })
+}
+
+#[test]
+fn create_claim_failed_with_insufficient_balance() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(4), claim.clone(), None),
+            Error::<Test>::InsufficientBalance
+        );
+        assert_eq!(Proofs::<Test>::get(&claim), None);
+    })
+}
+
+#[test]
+fn revoke_claim_releases_deposit() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone()
+        ));
+
+        assert_eq!(Deposits::<Test>::get(&claim), None);
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::<()>::ClaimDeposit.into(), &1),
+            0
+        );
+        assert_eq!(Balances::free_balance(1), 100);
+        System::assert_has_event(Event::<Test>::DepositReleased(1, 13).into());
+    })
+}
+
+#[test]
+fn transfer_claim_moves_deposit() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+
+        assert_ok!(PoeModule::transfer_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            2
+        ));
+
+        assert_eq!(Balances::total_balance(&1), 87);
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::<()>::ClaimDeposit.into(), &2),
+            13
+        );
+        assert_eq!(Balances::free_balance(2), 100);
+        System::assert_has_event(Event::<Test>::DepositTransfered(1, 2, 13).into());
+
+        // The new owner gets the deposit back when revoking.
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(2),
+            claim.clone()
+        ));
+        assert_eq!(Balances::free_balance(2), 113);
+    })
+}
+
+#[test]
+fn hash_claim_lifecycle_works() {
+    new_test_ext().execute_with(|| {
+        let hash = PoeModule::hash_of(b"a document far larger than MaxClaimLenth");
+        assert_eq!(
+            hash,
+            BlakeTwo256::hash(b"a document far larger than MaxClaimLenth")
+        );
+
+        assert_ok!(PoeModule::create_hash_claim(RuntimeOrigin::signed(1), hash));
+        assert_eq!(
+            HashProofs::<Test>::get(&hash),
+            Some(ClaimInfo {
+                owner: 1,
+                block_number: frame_system::Pallet::<Test>::block_number(),
+                timestamp: 0,
+            })
+        );
+        // 10 base + 32 bytes * 1 per byte
+        assert_eq!(HashDeposits::<Test>::get(&hash), Some(42));
+
+        assert_ok!(PoeModule::transfer_hash_claim(
+            RuntimeOrigin::signed(1),
+            hash,
+            2
+        ));
+        assert_eq!(
+            HashProofs::<Test>::get(&hash).map(|info| info.owner),
+            Some(2)
+        );
+
+        assert_ok!(PoeModule::revoke_hash_claim(RuntimeOrigin::signed(2), hash));
+        assert_eq!(HashProofs::<Test>::get(&hash), None);
+        assert_eq!(Balances::free_balance(1), 58);
+        assert_eq!(Balances::free_balance(2), 142);
+    })
+}
+
+#[test]
+fn hash_claim_failed_when_already_exist() {
+    new_test_ext().execute_with(|| {
+        let hash = PoeModule::hash_of(b"document");
+        let _ = PoeModule::create_hash_claim(RuntimeOrigin::signed(1), hash);
+
+        assert_noop!(
+            PoeModule::create_hash_claim(RuntimeOrigin::signed(2), hash),
+            Error::<Test>::ProofAlreadyExist
+        );
+    })
+}
+
+#[test]
+fn hash_claim_failed_with_wrong_owner() {
+    new_test_ext().execute_with(|| {
+        let hash = PoeModule::hash_of(b"document");
+        let _ = PoeModule::create_hash_claim(RuntimeOrigin::signed(1), hash);
+
+        assert_noop!(
+            PoeModule::revoke_hash_claim(RuntimeOrigin::signed(2), hash),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_noop!(
+            PoeModule::transfer_hash_claim(RuntimeOrigin::signed(2), hash, 3),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_noop!(
+            PoeModule::revoke_hash_claim(RuntimeOrigin::signed(1), PoeModule::hash_of(b"other")),
+            Error::<Test>::ClaimNotExist
+        );
+    })
+}
+
+#[test]
+fn owner_index_follows_claims() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        assert!(ClaimsOf::<Test>::contains_key(1, &claim));
+
+        let _ = PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2);
+        assert!(!ClaimsOf::<Test>::contains_key(1, &claim));
+        assert!(ClaimsOf::<Test>::contains_key(2, &claim));
+
+        let _ = PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim.clone());
+        assert!(!ClaimsOf::<Test>::contains_key(2, &claim));
+    })
+}
+
+#[test]
+fn claims_of_paginates() {
+    new_test_ext().execute_with(|| {
+        for i in 0..5u8 {
+            let claim = BoundedVec::try_from(vec![i]).unwrap();
+            assert_ok!(PoeModule::create_claim(
+                RuntimeOrigin::signed(1),
+                claim,
+                None
+            ));
+        }
+        let _ = PoeModule::create_claim(
+            RuntimeOrigin::signed(2),
+            BoundedVec::try_from(vec![9]).unwrap(),
+            None,
+        );
+
+        let (first, cursor) = PoeModule::claims_of(&1, None, 2);
+        assert_eq!(first.len(), 2);
+        assert!(cursor.is_some());
+
+        let (second, cursor) = PoeModule::claims_of(&1, cursor, 2);
+        assert_eq!(second.len(), 2);
+        assert!(cursor.is_some());
+
+        let (third, cursor) = PoeModule::claims_of(&1, cursor, 2);
+        assert_eq!(third.len(), 1);
+        assert_eq!(cursor, None);
+
+        let mut all: Vec<_> = first.into_iter().chain(second).chain(third).collect();
+        all.sort();
+        let expected: Vec<BoundedVec<u8, <Test as Config>::MaxClaimLenth>> = (0..5u8)
+            .map(|i| BoundedVec::try_from(vec![i]).unwrap())
+            .collect();
+        assert_eq!(all, expected);
+    })
+}
+
+#[test]
+fn hash_claims_of_follows_ownership() {
+    new_test_ext().execute_with(|| {
+        let first = H256::repeat_byte(1);
+        let second = H256::repeat_byte(2);
+        assert_ok!(PoeModule::create_hash_claim(
+            RuntimeOrigin::signed(1),
+            first
+        ));
+        assert_ok!(PoeModule::create_hash_claim(
+            RuntimeOrigin::signed(1),
+            second
+        ));
+
+        let (page, cursor) = PoeModule::hash_claims_of(&1, None, 1);
+        assert_eq!(page.len(), 1);
+        let (rest, cursor) = PoeModule::hash_claims_of(&1, cursor, 1);
+        assert_eq!(cursor, None);
+        let mut all: Vec<_> = page.into_iter().chain(rest).collect();
+        all.sort();
+        assert_eq!(all, vec![first, second]);
+
+        assert_ok!(PoeModule::transfer_hash_claim(
+            RuntimeOrigin::signed(1),
+            first,
+            2
+        ));
+        assert_ok!(PoeModule::revoke_hash_claim(
+            RuntimeOrigin::signed(1),
+            second
+        ));
+        assert_eq!(PoeModule::hash_claims_of(&1, None, 10), (vec![], None));
+        assert_eq!(PoeModule::hash_claims_of(&2, None, 10), (vec![first], None));
+        assert!(!HashClaimsOf::<Test>::contains_key(1, first));
+    })
+}
+
+#[test]
+fn transfer_claim_failed_when_direct_transfer_disabled() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        AllowDirectTransfer::set(false);
+
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2),
+            Error::<Test>::DirectTransferDisabled
+        );
+    })
+}
+
+#[test]
+fn offer_and_accept_claim_works() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+
+        assert_ok!(PoeModule::offer_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            2,
+            Some(10)
+        ));
+        assert_eq!(PendingTransfers::<Test>::get(&claim), Some((2, Some(11))));
+        // Ownership only changes once the offer is accepted.
+        assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(1));
+
+        assert_noop!(
+            PoeModule::accept_claim(RuntimeOrigin::signed(3), claim.clone()),
+            Error::<Test>::NotOfferRecipient
+        );
+        assert_ok!(PoeModule::accept_claim(
+            RuntimeOrigin::signed(2),
+            claim.clone()
+        ));
+
+        assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(2));
+        assert_eq!(PendingTransfers::<Test>::get(&claim), None);
+        assert!(ClaimsOf::<Test>::contains_key(2, &claim));
+        System::assert_has_event(Event::<Test>::OfferAccepted(2, claim).into());
+    })
+}
+
+#[test]
+fn accept_claim_failed_when_offer_expired() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        let _ = PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2, Some(5));
+
+        System::set_block_number(7);
+        assert_noop!(
+            PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()),
+            Error::<Test>::OfferExpired
+        );
+    })
+}
+
+#[test]
+fn cancel_offer_works() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        let _ = PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2, None);
+
+        assert_noop!(
+            PoeModule::cancel_offer(RuntimeOrigin::signed(2), claim.clone()),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_ok!(PoeModule::cancel_offer(
+            RuntimeOrigin::signed(1),
+            claim.clone()
+        ));
+        assert_noop!(
+            PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()),
+            Error::<Test>::OfferNotExist
+        );
+        assert_noop!(
+            PoeModule::cancel_offer(RuntimeOrigin::signed(1), claim.clone()),
+            Error::<Test>::OfferNotExist
+        );
+    })
+}
+
+#[test]
+fn anyone_can_cancel_an_expired_offer() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        let _ = PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2, Some(5));
+
+        System::set_block_number(6);
+        assert_noop!(
+            PoeModule::cancel_offer(RuntimeOrigin::signed(3), claim.clone()),
+            Error::<Test>::NotClaimOwner
+        );
+
+        System::set_block_number(7);
+        assert_ok!(PoeModule::cancel_offer(
+            RuntimeOrigin::signed(3),
+            claim.clone()
+        ));
+        assert_eq!(PendingTransfers::<Test>::get(&claim), None);
+        System::assert_last_event(Event::<Test>::OfferCancelled(3, claim).into());
+    })
+}
+
+#[test]
+fn expired_claims_are_pruned_on_initialize() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let other = BoundedVec::try_from(vec![4, 5, 6]).unwrap();
+        assert_ok!(PoeModule::create_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            Some(5)
+        ));
+        assert_ok!(PoeModule::create_claim(
+            RuntimeOrigin::signed(1),
+            other.clone(),
+            None
+        ));
+        assert_eq!(
+            ExpiryQueue::<Test>::get(5).into_inner(),
+            vec![claim.clone()]
+        );
+
+        PoeModule::on_initialize(4);
+        assert!(Proofs::<Test>::contains_key(&claim));
+
+        System::set_block_number(5);
+        PoeModule::on_initialize(5);
+        assert_eq!(Proofs::<Test>::get(&claim), None);
+        assert_eq!(ClaimExpiry::<Test>::get(&claim), None);
+        assert!(!ClaimsOf::<Test>::contains_key(1, &claim));
+        assert!(!ExpiryQueue::<Test>::contains_key(5));
+        assert!(Proofs::<Test>::contains_key(&other));
+        // Only the deposit of the claim that did not expire is still held.
+        assert_eq!(Balances::free_balance(1), 87);
+        System::assert_has_event(Event::<Test>::ClaimExpired(1, claim).into());
+    })
+}
+
+#[test]
+fn frozen_claims_do_not_expire() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        assert_ok!(PoeModule::create_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            Some(5)
+        ));
+        assert_ok!(PoeModule::freeze_claim(
+            RuntimeOrigin::root(),
+            claim.clone(),
+            reason(b"disputed")
+        ));
+
+        System::set_block_number(5);
+        PoeModule::on_initialize(5);
+        assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(1));
+        assert_eq!(ClaimExpiry::<Test>::get(&claim), None);
+        assert!(Frozen::<Test>::contains_key(&claim));
+        System::assert_last_event(
+            Event::<Test>::ExpiryFailed(claim, Error::<Test>::ClaimFrozen.into()).into(),
+        );
+    })
+}
+
+#[test]
+fn revoked_claim_leaves_expiry_queue() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), Some(5));
+
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone()
+        ));
+        assert!(!ExpiryQueue::<Test>::contains_key(5));
+
+        // Recreating the claim without expiry must survive block 5.
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        PoeModule::on_initialize(5);
+        assert!(Proofs::<Test>::contains_key(&claim));
+    })
+}
+
+#[test]
+fn create_claim_failed_with_invalid_expiry() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), Some(1)),
+            Error::<Test>::InvalidExpiry
+        );
+    })
+}
+
+#[test]
+fn create_claim_failed_when_expiry_queue_full() {
+    new_test_ext().execute_with(|| {
+        for i in 0..2u8 {
+            let claim = BoundedVec::try_from(vec![i]).unwrap();
+            assert_ok!(PoeModule::create_claim(
+                RuntimeOrigin::signed(1),
+                claim,
+                Some(5)
+            ));
+        }
+
+        let claim = BoundedVec::try_from(vec![9]).unwrap();
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(1), claim, Some(5)),
+            Error::<Test>::ExpiryQueueFull
+        );
+    })
+}
+
+#[test]
+fn set_claim_metadata_works() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        let content_type = BoundedVec::try_from(b"text/plain".to_vec()).unwrap();
+        let uri = BoundedVec::try_from(b"ipfs://bafy".to_vec()).unwrap();
+        let description = BoundedVec::try_from(b"contract".to_vec()).unwrap();
+
+        assert_noop!(
+            PoeModule::set_claim_metadata(
+                RuntimeOrigin::signed(2),
+                claim.clone(),
+                content_type.clone(),
+                uri.clone(),
+                description.clone()
+            ),
+            Error::<Test>::NotClaimOwner
+        );
+        assert_ok!(PoeModule::set_claim_metadata(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            content_type.clone(),
+            uri.clone(),
+            description.clone()
+        ));
+        assert_eq!(
+            Metadata::<Test>::get(&claim),
+            Some(ClaimMetadata {
+                content_type,
+                uri,
+                description
+            })
+        );
+
+        // Metadata goes away with the claim.
+        let _ = PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone());
+        assert_eq!(Metadata::<Test>::get(&claim), None);
+    })
+}
+
+#[test]
+fn set_hash_claim_metadata_works() {
+    new_test_ext().execute_with(|| {
+        let hash = PoeModule::hash_of(b"document");
+        let _ = PoeModule::create_hash_claim(RuntimeOrigin::signed(1), hash);
+        let uri = BoundedVec::try_from(b"ipfs://bafy".to_vec()).unwrap();
+
+        assert_ok!(PoeModule::set_hash_claim_metadata(
+            RuntimeOrigin::signed(1),
+            hash,
+            Default::default(),
+            uri.clone(),
+            Default::default()
+        ));
+        assert_eq!(HashMetadata::<Test>::get(&hash).map(|m| m.uri), Some(uri));
+    })
+}
+
+#[test]
+fn history_records_ownership_chain() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        System::set_block_number(2);
+        let _ = PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2);
+        System::set_block_number(3);
+        let _ = PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim.clone());
+
+        let record = |owner, block_number, action| ProvenanceRecord {
+            owner,
+            block_number,
+            action,
+        };
+        assert_eq!(
+            History::<Test>::get(&claim).into_inner(),
+            vec![
+                record(1, 1, ClaimAction::Created),
+                record(2, 2, ClaimAction::Transferred),
+                record(2, 3, ClaimAction::Revoked),
+            ]
+        );
+
+        // The oldest record is dropped once the history is full.
+        System::set_block_number(4);
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(3), claim.clone(), None);
+        assert_eq!(
+            History::<Test>::get(&claim).into_inner(),
+            vec![
+                record(2, 2, ClaimAction::Transferred),
+                record(2, 3, ClaimAction::Revoked),
+                record(3, 4, ClaimAction::Created),
+            ]
+        );
+    })
+}
+
+#[test]
+fn hash_history_records_ownership_chain() {
+    new_test_ext().execute_with(|| {
+        let hash = PoeModule::hash_of(b"document");
+        let _ = PoeModule::create_hash_claim(RuntimeOrigin::signed(1), hash);
+        let _ = PoeModule::transfer_hash_claim(RuntimeOrigin::signed(1), hash, 2);
+
+        let actions: Vec<_> = HashHistory::<Test>::get(&hash)
+            .into_iter()
+            .map(|r| (r.owner, r.action))
+            .collect();
+        assert_eq!(
+            actions,
+            vec![(1, ClaimAction::Created), (2, ClaimAction::Transferred)]
+        );
+    })
+}
+
+fn leaf(document: H256) -> H256 {
+    BlakeTwo256::hash_of(&(MERKLE_LEAF_PREFIX, document))
+}
+
+fn node(a: H256, b: H256) -> H256 {
+    if a <= b {
+        BlakeTwo256::hash_of(&(MERKLE_NODE_PREFIX, a, b))
+    } else {
+        BlakeTwo256::hash_of(&(MERKLE_NODE_PREFIX, b, a))
+    }
+}
+
+#[test]
+fn merkle_claim_proves_inclusion() {
+    new_test_ext().execute_with(|| {
+        let leaves: Vec<H256> = (0..3u8).map(|i| PoeModule::hash_of(&[i])).collect();
+        let left = node(leaf(leaves[0]), leaf(leaves[1]));
+        let root = node(left, leaf(leaves[2]));
+
+        assert_ok!(PoeModule::create_merkle_claim(
+            RuntimeOrigin::signed(1),
+            root
+        ));
+        assert!(HashProofs::<Test>::contains_key(root));
+
+        let proof = BoundedVec::try_from(vec![leaf(leaves[0]), leaf(leaves[2])]).unwrap();
+        assert_ok!(PoeModule::verify_inclusion(
+            RuntimeOrigin::signed(2),
+            root,
+            leaves[1],
+            proof
+        ));
+        System::assert_last_event(Event::<Test>::InclusionVerified(root, leaves[1]).into());
+
+        assert!(PoeModule::is_included(root, leaves[2], &[left]));
+        assert!(!PoeModule::is_included(root, leaves[2], &[leaf(leaves[0])]));
+        // An inner node is not a leaf of the tree.
+        assert!(!PoeModule::is_included(root, left, &[leaf(leaves[2])]));
+
+        assert_noop!(
+            PoeModule::verify_inclusion(
+                RuntimeOrigin::signed(2),
+                root,
+                PoeModule::hash_of(b"not a leaf"),
+                BoundedVec::try_from(vec![left]).unwrap()
+            ),
+            Error::<Test>::InvalidMerkleProof
+        );
+    })
+}
+
+#[test]
+fn verify_inclusion_requires_merkle_claim() {
+    new_test_ext().execute_with(|| {
+        let document = PoeModule::hash_of(b"document");
+        // A plain hash claim is not a Merkle root.
+        let _ = PoeModule::create_hash_claim(RuntimeOrigin::signed(1), document);
+
+        assert_noop!(
+            PoeModule::verify_inclusion(
+                RuntimeOrigin::signed(1),
+                document,
+                document,
+                Default::default()
+            ),
+            Error::<Test>::MerkleRootNotExist
+        );
+
+        let root = node(leaf(document), leaf(document));
+        let _ = PoeModule::create_merkle_claim(RuntimeOrigin::signed(1), root);
+        let _ = PoeModule::revoke_hash_claim(RuntimeOrigin::signed(1), root);
+        assert!(!MerkleRoots::<Test>::contains_key(root));
+    })
+}
+
+fn sign_claim(owner: u64, claim: &BoundedVec<u8, ConstU32<10>>, deadline: u64) -> TestSignature {
+    let payload = SignedClaimPayload {
+        claim: claim.clone(),
+        owner,
+        nonce: SignerNonces::<Test>::get(owner),
+        deadline,
+        pallet_index: PoeModule::index() as u32,
+        genesis_hash: System::block_hash(0),
+    };
+    TestSignature(owner, payload.encode())
+}
+
+#[test]
+fn create_claim_signed_works() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        // Account 4 cannot afford the deposit itself.
+        let signature = sign_claim(4, &claim, 5);
+
+        assert_ok!(PoeModule::create_claim_signed(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            4,
+            signature,
+            5
+        ));
+
+        assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(4));
+        assert!(ClaimsOf::<Test>::contains_key(4, &claim));
+        assert_eq!(Deposits::<Test>::get(&claim), Some((1, 13)));
+        assert_eq!(SignerNonces::<Test>::get(4), 1);
+        System::assert_last_event(Event::<Test>::ClaimSponsored(1, 4, claim.clone()).into());
+
+        // The relayer gets the deposit back when the owner revokes the claim.
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(4),
+            claim.clone()
+        ));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::<()>::ClaimDeposit.into(), &1),
+            0
+        );
+        assert_eq!(Balances::free_balance(1), 100);
+    })
+}
+
+#[test]
+fn sponsored_deposit_stays_with_the_relayer_on_transfer() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let signature = sign_claim(4, &claim, 5);
+        assert_ok!(PoeModule::create_claim_signed(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            4,
+            signature,
+            5
+        ));
+
+        // Neither a self-transfer nor a transfer to someone else moves the relayer's deposit.
+        assert_ok!(PoeModule::transfer_claim(
+            RuntimeOrigin::signed(4),
+            claim.clone(),
+            4
+        ));
+        assert_ok!(PoeModule::transfer_claim(
+            RuntimeOrigin::signed(4),
+            claim.clone(),
+            2
+        ));
+        assert_eq!(Deposits::<Test>::get(&claim), Some((1, 13)));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::<()>::ClaimDeposit.into(), &2),
+            0
+        );
+
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(2),
+            claim.clone()
+        ));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::<()>::ClaimDeposit.into(), &1),
+            0
+        );
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::free_balance(2), 100);
+        assert_eq!(Balances::free_balance(4), 5);
+    })
+}
+
+#[test]
+fn create_claim_signed_rejects_bad_signature() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let other = BoundedVec::try_from(vec![4, 5, 6]).unwrap();
+
+        // Signed by someone other than the owner.
+        assert_noop!(
+            PoeModule::create_claim_signed(
+                RuntimeOrigin::signed(1),
+                claim.clone(),
+                4,
+                sign_claim(2, &claim, 5),
+                5
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        // Signed for a different claim.
+        assert_noop!(
+            PoeModule::create_claim_signed(
+                RuntimeOrigin::signed(1),
+                claim.clone(),
+                4,
+                sign_claim(4, &other, 5),
+                5
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        // Signed with a different deadline.
+        assert_noop!(
+            PoeModule::create_claim_signed(
+                RuntimeOrigin::signed(1),
+                claim.clone(),
+                4,
+                sign_claim(4, &claim, 5),
+                6
+            ),
+            Error::<Test>::InvalidSignature
+        );
+    })
+}
+
+#[test]
+fn create_claim_signed_cannot_be_replayed() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let signature = sign_claim(4, &claim, 5);
+        let _ = PoeModule::create_claim_signed(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            4,
+            signature.clone(),
+            5,
+        );
+        let _ = PoeModule::revoke_claim(RuntimeOrigin::signed(4), claim.clone());
+
+        assert_noop!(
+            PoeModule::create_claim_signed(RuntimeOrigin::signed(1), claim, 4, signature, 5),
+            Error::<Test>::InvalidSignature
+        );
+    })
+}
+
+#[test]
+fn create_claim_signed_is_bound_to_the_instance_and_chain() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let signature = sign_claim(4, &claim, 5);
+
+        // Both instances start with the same nonce, yet the signature only works on one.
+        assert_noop!(
+            PoeArtifacts::create_claim_signed(
+                RuntimeOrigin::signed(1),
+                claim.clone(),
+                4,
+                signature,
+                5
+            ),
+            Error::<Test, Instance1>::InvalidSignature
+        );
+
+        let payload = SignedClaimPayload {
+            claim: claim.clone(),
+            owner: 4,
+            nonce: 0,
+            deadline: 5,
+            pallet_index: PoeModule::index() as u32,
+            genesis_hash: H256::repeat_byte(1),
+        };
+        assert_noop!(
+            PoeModule::create_claim_signed(
+                RuntimeOrigin::signed(1),
+                claim,
+                4,
+                TestSignature(4, payload.encode()),
+                5
+            ),
+            Error::<Test>::InvalidSignature
+        );
+    })
+}
+
+#[test]
+fn create_claim_signed_fails_after_deadline() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let signature = sign_claim(4, &claim, 5);
+        System::set_block_number(6);
+
+        assert_noop!(
+            PoeModule::create_claim_signed(RuntimeOrigin::signed(1), claim, 4, signature, 5),
+            Error::<Test>::SignatureExpired
+        );
+    })
+}
+
+#[test]
+fn claim_operator_can_transfer_and_revoke() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+
+        assert_ok!(PoeModule::approve_operator(
+            RuntimeOrigin::signed(1),
+            3,
+            Some(claim.clone())
+        ));
+        assert!(PoeModule::is_operator(&1, &3, &claim));
+        System::assert_last_event(
+            Event::<Test>::OperatorApproved(1, 3, Some(claim.clone())).into(),
+        );
+
+        assert_ok!(PoeModule::transfer_claim(
+            RuntimeOrigin::signed(3),
+            claim.clone(),
+            2
+        ));
+        assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(2));
+        System::assert_last_event(Event::<Test>::ClaimTransfered(1, 2, claim.clone(), 0).into());
+
+        // The approval does not follow the claim to its new owner.
+        assert_eq!(ClaimOperators::<Test>::get(&claim), None);
+        assert_noop!(
+            PoeModule::revoke_claim(RuntimeOrigin::signed(3), claim.clone()),
+            Error::<Test>::NotClaimOwner
+        );
+
+        let _ = PoeModule::approve_operator(RuntimeOrigin::signed(2), 3, Some(claim.clone()));
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(3),
+            claim.clone()
+        ));
+        assert_eq!(Proofs::<Test>::get(&claim), None);
+        System::assert_last_event(Event::<Test>::ClaimRevoked(2, claim).into());
+    })
+}
+
+#[test]
+fn account_operator_can_manage_every_claim() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let other = BoundedVec::try_from(vec![4, 5, 6]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+
+        assert_ok!(PoeModule::approve_operator(
+            RuntimeOrigin::signed(1),
+            3,
+            None
+        ));
+        // Claims created after the approval are covered too.
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), other.clone(), None);
+
+        assert_ok!(PoeModule::transfer_claim(
+            RuntimeOrigin::signed(3),
+            claim.clone(),
+            2
+        ));
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(3),
+            other.clone()
+        ));
+
+        // The claim now belongs to 2, who has not approved 3.
+        assert_noop!(
+            PoeModule::revoke_claim(RuntimeOrigin::signed(3), claim),
+            Error::<Test>::NotClaimOwner
+        );
+    })
+}
+
+#[test]
+fn revoke_operator_works() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        let _ = PoeModule::approve_operator(RuntimeOrigin::signed(1), 3, None);
+        let _ = PoeModule::approve_operator(RuntimeOrigin::signed(1), 4, Some(claim.clone()));
+
+        assert_ok!(PoeModule::revoke_operator(
+            RuntimeOrigin::signed(1),
+            3,
+            None
+        ));
+        assert_ok!(PoeModule::revoke_operator(
+            RuntimeOrigin::signed(1),
+            4,
+            Some(claim.clone())
+        ));
+        System::assert_last_event(Event::<Test>::OperatorRevoked(1, 4, Some(claim.clone())).into());
+
+        for operator in [3, 4] {
+            assert_noop!(
+                PoeModule::transfer_claim(RuntimeOrigin::signed(operator), claim.clone(), 2),
+                Error::<Test>::NotClaimOwner
+            );
+        }
+        assert_noop!(
+            PoeModule::revoke_operator(RuntimeOrigin::signed(1), 3, None),
+            Error::<Test>::ApprovalNotExist
+        );
+    })
+}
+
+#[test]
+fn approve_operator_requires_claim_owner() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        let _ = PoeModule::approve_operator(RuntimeOrigin::signed(1), 3, None);
+
+        // Operators cannot delegate their approval further, or make offers.
+        assert_noop!(
+            PoeModule::approve_operator(RuntimeOrigin::signed(3), 2, Some(claim.clone())),
+            Error::<Test>::NotApproved
+        );
+        assert_noop!(
+            PoeModule::offer_claim(RuntimeOrigin::signed(3), claim.clone(), 2, None),
+            Error::<Test>::NotApproved
+        );
+        assert_noop!(
+            PoeModule::revoke_operator(RuntimeOrigin::signed(2), 3, Some(claim)),
+            Error::<Test>::NotClaimOwner
+        );
+    })
+}
+
+#[test]
+fn migrate_v0_to_v1_works() {
+    new_test_ext().execute_with(|| {
+        let claim: BoundedVec<u8, ConstU32<10>> = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        frame_support::storage::unhashed::put(
+            &Proofs::<Test>::hashed_key_for(&claim),
+            &(1u64, 1u64),
+        );
+        StorageVersion::new(0).put::<PoeModule>();
+
+        migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            migrations::v1::Proofs::<Test, ()>::get(&claim),
+            Some(migrations::v1::ClaimInfo {
+                owner: 1,
+                block_number: 1
+            })
+        );
+        assert_eq!(StorageVersion::get::<PoeModule>(), 1);
+
+        // Running it again is a no-op.
+        migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(
+            migrations::v1::Proofs::<Test, ()>::get(&claim).map(|info| info.owner),
+            Some(1)
+        );
+    })
+}
+
+fn genesis_ext(claims: Vec<(Vec<u8>, u64)>) -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    crate::GenesisConfig::<Test> {
+        claims,
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    t.into()
+}
+
+#[test]
+fn genesis_config_registers_claims() {
+    genesis_ext(vec![(vec![1, 2, 3], 1), (vec![4, 5, 6], 2)]).execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        assert_eq!(
+            Proofs::<Test>::get(&claim),
+            Some(ClaimInfo {
+                owner: 1,
+                block_number: 0,
+                timestamp: 0,
+            })
+        );
+        assert!(ClaimsOf::<Test>::contains_key(1, &claim));
+        assert_eq!(History::<Test>::get(&claim).len(), 1);
+        assert_eq!(Deposits::<Test>::get(&claim), None);
+
+        let other = BoundedVec::try_from(vec![4, 5, 6]).unwrap();
+        assert_eq!(Proofs::<Test>::get(&other).map(|info| info.owner), Some(2));
+    })
+}
+
+#[test]
+#[should_panic(expected = "duplicate claim in genesis")]
+fn genesis_config_rejects_duplicate_claims() {
+    genesis_ext(vec![(vec![1, 2, 3], 1), (vec![1, 2, 3], 2)]);
+}
+
+#[test]
+#[should_panic(expected = "genesis claim is longer than MaxClaimLenth")]
+fn genesis_config_rejects_long_claims() {
+    genesis_ext(vec![(vec![0; 11], 1)]);
+}
+
+fn reason(text: &[u8]) -> BoundedVec<u8, ConstU32<16>> {
+    BoundedVec::try_from(text.to_vec()).unwrap()
+}
+
+#[test]
+fn frozen_claim_rejects_owner_actions() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        let _ = PoeModule::approve_operator(RuntimeOrigin::signed(1), 3, None);
+
+        assert_ok!(PoeModule::freeze_claim(
+            RuntimeOrigin::root(),
+            claim.clone(),
+            reason(b"disputed")
+        ));
+        System::assert_last_event(
+            Event::<Test>::ClaimFrozen(claim.clone(), reason(b"disputed")).into(),
+        );
+
+        for who in [1, 3] {
+            assert_noop!(
+                PoeModule::revoke_claim(RuntimeOrigin::signed(who), claim.clone()),
+                Error::<Test>::ClaimFrozen
+            );
+            assert_noop!(
+                PoeModule::transfer_claim(RuntimeOrigin::signed(who), claim.clone(), 2),
+                Error::<Test>::ClaimFrozen
+            );
+        }
+        assert_noop!(
+            PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2, None),
+            Error::<Test>::ClaimFrozen
+        );
+        assert_noop!(
+            PoeModule::set_claim_metadata(
+                RuntimeOrigin::signed(1),
+                claim.clone(),
+                Default::default(),
+                Default::default(),
+                Default::default()
+            ),
+            Error::<Test>::ClaimFrozen
+        );
+
+        assert_ok!(PoeModule::unfreeze_claim(
+            RuntimeOrigin::root(),
+            claim.clone(),
+            reason(b"resolved")
+        ));
+        System::assert_last_event(
+            Event::<Test>::ClaimUnfrozen(claim.clone(), reason(b"resolved")).into(),
+        );
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim));
+    })
+}
+
+#[test]
+fn frozen_claim_offer_cannot_be_accepted() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        let _ = PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2, None);
+        let _ = PoeModule::freeze_claim(RuntimeOrigin::root(), claim.clone(), reason(b""));
+
+        assert_noop!(
+            PoeModule::accept_claim(RuntimeOrigin::signed(2), claim),
+            Error::<Test>::ClaimFrozen
+        );
+    })
+}
+
+#[test]
+fn freeze_claim_requires_force_origin() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+
+        assert_noop!(
+            PoeModule::freeze_claim(RuntimeOrigin::signed(1), claim.clone(), reason(b"")),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            PoeModule::force_revoke_claim(RuntimeOrigin::signed(2), claim.clone(), reason(b"")),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            PoeModule::unfreeze_claim(RuntimeOrigin::root(), claim.clone(), reason(b"")),
+            Error::<Test>::ClaimNotFrozen
+        );
+
+        let _ = PoeModule::freeze_claim(RuntimeOrigin::root(), claim.clone(), reason(b""));
+        assert_noop!(
+            PoeModule::freeze_claim(RuntimeOrigin::root(), claim, reason(b"")),
+            Error::<Test>::ClaimFrozen
+        );
+    })
+}
+
+#[test]
+fn force_transfer_claim_works() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        let _ = PoeModule::freeze_claim(RuntimeOrigin::root(), claim.clone(), reason(b"fraud"));
+
+        assert_ok!(PoeModule::force_transfer_claim(
+            RuntimeOrigin::root(),
+            claim.clone(),
+            2,
+            reason(b"fraud")
+        ));
+
+        assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(2));
+        assert!(!Frozen::<Test>::contains_key(&claim));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::<()>::ClaimDeposit.into(), &2),
+            13
+        );
+        System::assert_last_event(
+            Event::<Test>::ClaimForceTransferred(1, 2, claim.clone(), reason(b"fraud")).into(),
+        );
+
+        // The new owner is free to manage the claim.
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim));
+    })
+}
+
+#[test]
+fn force_revoke_claim_works() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        let _ = PoeModule::freeze_claim(RuntimeOrigin::root(), claim.clone(), reason(b"fraud"));
+
+        assert_ok!(PoeModule::force_revoke_claim(
+            RuntimeOrigin::root(),
+            claim.clone(),
+            reason(b"fraud")
+        ));
+
+        assert_eq!(Proofs::<Test>::get(&claim), None);
+        assert!(!Frozen::<Test>::contains_key(&claim));
+        assert_eq!(Balances::free_balance(1), 100);
+        System::assert_last_event(
+            Event::<Test>::ClaimForceRevoked(1, claim, reason(b"fraud")).into(),
+        );
+    })
+}
+
+#[test]
+fn frozen_hash_claim_rejects_owner_actions() {
+    new_test_ext().execute_with(|| {
+        let hash = PoeModule::hash_of(b"document");
+        let _ = PoeModule::create_hash_claim(RuntimeOrigin::signed(1), hash);
+
+        assert_noop!(
+            PoeModule::freeze_hash_claim(RuntimeOrigin::signed(1), hash, reason(b"")),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(PoeModule::freeze_hash_claim(
+            RuntimeOrigin::root(),
+            hash,
+            reason(b"disputed")
+        ));
+        System::assert_last_event(Event::<Test>::HashClaimFrozen(hash, reason(b"disputed")).into());
+        assert_noop!(
+            PoeModule::freeze_hash_claim(RuntimeOrigin::root(), hash, reason(b"")),
+            Error::<Test>::ClaimFrozen
+        );
+
+        assert_noop!(
+            PoeModule::revoke_hash_claim(RuntimeOrigin::signed(1), hash),
+            Error::<Test>::ClaimFrozen
+        );
+        assert_noop!(
+            PoeModule::transfer_hash_claim(RuntimeOrigin::signed(1), hash, 2),
+            Error::<Test>::ClaimFrozen
+        );
+        assert_noop!(
+            PoeModule::set_hash_claim_metadata(
+                RuntimeOrigin::signed(1),
+                hash,
+                Default::default(),
+                Default::default(),
+                Default::default()
+            ),
+            Error::<Test>::ClaimFrozen
+        );
+
+        assert_ok!(PoeModule::unfreeze_hash_claim(
+            RuntimeOrigin::root(),
+            hash,
+            reason(b"resolved")
+        ));
+        System::assert_last_event(
+            Event::<Test>::HashClaimUnfrozen(hash, reason(b"resolved")).into(),
+        );
+        assert_noop!(
+            PoeModule::unfreeze_hash_claim(RuntimeOrigin::root(), hash, reason(b"")),
+            Error::<Test>::ClaimNotFrozen
+        );
+        assert_ok!(PoeModule::revoke_hash_claim(RuntimeOrigin::signed(1), hash));
+    })
+}
+
+#[test]
+fn force_transfer_hash_claim_works() {
+    new_test_ext().execute_with(|| {
+        let hash = PoeModule::hash_of(b"document");
+        let _ = PoeModule::create_hash_claim(RuntimeOrigin::signed(1), hash);
+        let _ = PoeModule::freeze_hash_claim(RuntimeOrigin::root(), hash, reason(b"fraud"));
+
+        assert_ok!(PoeModule::force_transfer_hash_claim(
+            RuntimeOrigin::root(),
+            hash,
+            2,
+            reason(b"fraud")
+        ));
+
+        assert_eq!(
+            HashProofs::<Test>::get(hash).map(|info| info.owner),
+            Some(2)
+        );
+        assert!(!HashFrozen::<Test>::contains_key(hash));
+        // 10 base + 32 bytes * 1 per byte
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::<()>::ClaimDeposit.into(), &2),
+            42
+        );
+        System::assert_last_event(
+            Event::<Test>::HashClaimForceTransferred(1, 2, hash, reason(b"fraud")).into(),
+        );
+
+        // The new owner is free to manage the claim.
+        assert_ok!(PoeModule::revoke_hash_claim(RuntimeOrigin::signed(2), hash));
+    })
+}
+
+#[test]
+fn force_revoke_hash_claim_works() {
+    new_test_ext().execute_with(|| {
+        let hash = PoeModule::hash_of(b"document");
+        let _ = PoeModule::create_merkle_claim(RuntimeOrigin::signed(1), hash);
+        let _ = PoeModule::freeze_hash_claim(RuntimeOrigin::root(), hash, reason(b"fraud"));
+
+        assert_noop!(
+            PoeModule::force_revoke_hash_claim(RuntimeOrigin::signed(2), hash, reason(b"")),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(PoeModule::force_revoke_hash_claim(
+            RuntimeOrigin::root(),
+            hash,
+            reason(b"fraud")
+        ));
+
+        assert_eq!(HashProofs::<Test>::get(hash), None);
+        assert!(!HashFrozen::<Test>::contains_key(hash));
+        assert!(!MerkleRoots::<Test>::contains_key(hash));
+        assert_eq!(Balances::free_balance(1), 100);
+        System::assert_last_event(
+            Event::<Test>::HashClaimForceRevoked(1, hash, reason(b"fraud")).into(),
+        );
+    })
+}
+
+#[test]
+fn instances_are_independent() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+
+        // The same claim can be registered by someone else in the other registry.
+        let artifact = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        assert_ok!(PoeArtifacts::create_claim(
+            RuntimeOrigin::signed(2),
+            artifact.clone(),
+            None
+        ));
+        assert_eq!(
+            Proofs::<Test, Instance1>::get(&artifact).map(|info| info.owner),
+            Some(2)
+        );
+        assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(1));
+        // Each registry uses its own deposit.
+        assert_eq!(Balances::free_balance(1), 87);
+        assert_eq!(Balances::free_balance(2), 95);
+
+        // and its own maximum claim length.
+        let long = BoundedVec::try_from(vec![0; 20]).unwrap();
+        assert_ok!(PoeArtifacts::create_claim(
+            RuntimeOrigin::signed(2),
+            long,
+            None
+        ));
+
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim));
+        assert!(Proofs::<Test, Instance1>::contains_key(&artifact));
+    })
+}
+
+#[test]
+fn instances_expire_their_own_claims() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let artifact = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), Some(5));
+        assert_ok!(PoeArtifacts::create_claim(
+            RuntimeOrigin::signed(2),
+            artifact.clone(),
+            Some(5)
+        ));
+
+        System::set_block_number(5);
+        PoeArtifacts::on_initialize(5);
+        assert!(!Proofs::<Test, Instance1>::contains_key(&artifact));
+        assert!(Proofs::<Test>::contains_key(&claim));
+        assert_eq!(Balances::free_balance(2), 100);
+        System::assert_last_event(Event::<Test, Instance1>::ClaimExpired(2, artifact).into());
+
+        PoeModule::on_initialize(5);
+        assert!(!Proofs::<Test>::contains_key(&claim));
+    })
+}
+
+#[test]
+fn instances_keep_their_own_hash_claims() {
+    new_test_ext().execute_with(|| {
+        let hash = PoeModule::hash_of(b"document");
+        assert_ok!(PoeModule::create_hash_claim(RuntimeOrigin::signed(1), hash));
+        assert_ok!(PoeArtifacts::create_hash_claim(
+            RuntimeOrigin::signed(2),
+            hash
+        ));
+        System::assert_last_event(Event::<Test, Instance1>::HashClaimCreated(2, hash, 0).into());
+
+        // Freezing the claim in one registry leaves the other alone.
+        let _ = PoeModule::freeze_hash_claim(RuntimeOrigin::root(), hash, reason(b""));
+        assert_ok!(PoeArtifacts::transfer_hash_claim(
+            RuntimeOrigin::signed(2),
+            hash,
+            3
+        ));
+        assert_eq!(
+            HashProofs::<Test, Instance1>::get(hash).map(|info| info.owner),
+            Some(3)
+        );
+        assert_eq!(
+            HashProofs::<Test>::get(hash).map(|info| info.owner),
+            Some(1)
+        );
+    })
+}
+
+#[test]
+fn instances_migrate_independently() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(3).put::<PoeModule>();
+        StorageVersion::new(3).put::<PoeArtifacts>();
+
+        migrations::v4::MigrateV3ToV4::<Test, Instance1>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<PoeArtifacts>(), 4);
+        assert_eq!(StorageVersion::get::<PoeModule>(), 3);
+    })
+}
+
+#[test]
+fn claim_counts_are_tracked() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let other = BoundedVec::try_from(vec![4, 5, 6]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), other.clone(), None);
+        assert_eq!(Proofs::<Test>::count(), 2);
+        assert_eq!(ClaimCount::<Test>::get(1), 2);
+
+        let _ = PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2);
+        assert_eq!(ClaimCount::<Test>::get(1), 1);
+        assert_eq!(ClaimCount::<Test>::get(2), 1);
+
+        let _ = PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim);
+        assert_eq!(Proofs::<Test>::count(), 1);
+        assert_eq!(ClaimCount::<Test>::get(2), 0);
+    })
+}
+
+#[test]
+fn create_claim_fails_over_account_quota() {
+    new_test_ext().execute_with(|| {
+        MaxClaimsPerAccount::set(1);
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let other = BoundedVec::try_from(vec![4, 5, 6]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(1), other.clone(), None),
+            Error::<Test>::TooManyClaims
+        );
+        // Other accounts have their own quota.
+        assert_ok!(PoeModule::create_claim(
+            RuntimeOrigin::signed(2),
+            other.clone(),
+            None
+        ));
+        // Transfers count against the quota of the receiver.
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim, 2),
+            Error::<Test>::TooManyClaims
+        );
+    })
+}
+
+#[test]
+fn create_claim_fails_over_total_cap() {
+    new_test_ext().execute_with(|| {
+        MaxTotalClaims::set(Some(1));
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let other = BoundedVec::try_from(vec![4, 5, 6]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(2), other.clone(), None),
+            Error::<Test>::TooManyClaims
+        );
+        // Moving a claim does not add to the total.
+        assert_ok!(PoeModule::transfer_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            2
+        ));
+
+        let _ = PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim);
+        assert_ok!(PoeModule::create_claim(
+            RuntimeOrigin::signed(2),
+            other,
+            None
+        ));
+    })
+}
+
+#[test]
+fn hash_claims_count_against_quota() {
+    new_test_ext().execute_with(|| {
+        MaxClaimsPerAccount::set(2);
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim, None);
+        assert_ok!(PoeModule::create_hash_claim(
+            RuntimeOrigin::signed(1),
+            PoeModule::hash_of(b"document")
+        ));
+        assert_eq!(ClaimCount::<Test>::get(1), 2);
+
+        let root = PoeModule::hash_of(b"root");
+        assert_noop!(
+            PoeModule::create_hash_claim(RuntimeOrigin::signed(1), root),
+            Error::<Test>::TooManyClaims
+        );
+        assert_noop!(
+            PoeModule::create_merkle_claim(RuntimeOrigin::signed(1), root),
+            Error::<Test>::TooManyClaims
+        );
+        assert_noop!(
+            PoeModule::create_claim_with_content(
+                RuntimeOrigin::signed(1),
+                BoundedVec::try_from(b"content".to_vec()).unwrap()
+            ),
+            Error::<Test>::TooManyClaims
+        );
+
+        // Transfers count against the quota of the receiver.
+        let _ = PoeModule::create_hash_claim(RuntimeOrigin::signed(2), root);
+        let other = BoundedVec::try_from(vec![4, 5, 6]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(2), other, None);
+        assert_noop!(
+            PoeModule::transfer_hash_claim(
+                RuntimeOrigin::signed(1),
+                PoeModule::hash_of(b"document"),
+                2
+            ),
+            Error::<Test>::TooManyClaims
+        );
+
+        assert_ok!(PoeModule::revoke_hash_claim(RuntimeOrigin::signed(2), root));
+        assert_eq!(ClaimCount::<Test>::get(2), 1);
+        assert_ok!(PoeModule::transfer_hash_claim(
+            RuntimeOrigin::signed(1),
+            PoeModule::hash_of(b"document"),
+            2
+        ));
+        assert_eq!(ClaimCount::<Test>::get(1), 1);
+        assert_eq!(ClaimCount::<Test>::get(2), 2);
+    })
+}
+
+#[test]
+fn hash_claims_count_against_total_cap() {
+    new_test_ext().execute_with(|| {
+        MaxTotalClaims::set(Some(2));
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        assert_ok!(PoeModule::create_merkle_claim(
+            RuntimeOrigin::signed(2),
+            PoeModule::hash_of(b"root")
+        ));
+        assert_eq!(HashProofs::<Test>::count(), 1);
+
+        let other = BoundedVec::try_from(vec![4, 5, 6]).unwrap();
+        assert_noop!(
+            PoeModule::create_claim(RuntimeOrigin::signed(2), other, None),
+            Error::<Test>::TooManyClaims
+        );
+        assert_noop!(
+            PoeModule::create_hash_claim(RuntimeOrigin::signed(1), PoeModule::hash_of(b"document")),
+            Error::<Test>::TooManyClaims
+        );
+
+        let _ = PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim);
+        assert_ok!(PoeModule::create_hash_claim(
+            RuntimeOrigin::signed(1),
+            PoeModule::hash_of(b"document")
+        ));
+    })
+}
+
+#[test]
+fn migrate_v1_to_v2_works() {
+    new_test_ext().execute_with(|| {
+        for (i, owner) in [1u64, 1, 2].into_iter().enumerate() {
+            let claim: BoundedVec<u8, ConstU32<10>> = BoundedVec::try_from(vec![i as u8]).unwrap();
+            frame_support::storage::unhashed::put(
+                &Proofs::<Test>::hashed_key_for(&claim),
+                &migrations::v1::ClaimInfo {
+                    owner,
+                    block_number: 1u64,
+                },
+            );
+        }
+        StorageVersion::new(1).put::<PoeModule>();
+
+        migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Proofs::<Test>::count(), 3);
+        assert_eq!(ClaimCount::<Test>::get(1), 2);
+        assert_eq!(ClaimCount::<Test>::get(2), 1);
+        assert_eq!(StorageVersion::get::<PoeModule>(), 2);
+    })
+}
+
+#[test]
+fn migrate_v2_to_v3_works() {
+    new_test_ext().execute_with(|| {
+        let claim: BoundedVec<u8, ConstU32<10>> = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        migrations::v1::Proofs::<Test, ()>::insert(
+            &claim,
+            migrations::v1::ClaimInfo {
+                owner: 1,
+                block_number: 1,
+            },
+        );
+        StorageVersion::new(2).put::<PoeModule>();
+
+        migrations::v3::MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            Proofs::<Test>::get(&claim),
+            Some(ClaimInfo {
+                owner: 1,
+                block_number: 1,
+                timestamp: 0,
+            })
+        );
+        assert_eq!(StorageVersion::get::<PoeModule>(), 3);
+    })
+}
+
+#[test]
+fn migrate_v3_to_v4_works() {
+    new_test_ext().execute_with(|| {
+        let hash = PoeModule::hash_of(b"document");
+        frame_support::storage::unhashed::put(
+            &HashProofs::<Test>::hashed_key_for(hash),
+            &(1u64, 1u64),
+        );
+        StorageVersion::new(3).put::<PoeModule>();
+
+        migrations::v4::MigrateV3ToV4::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            HashProofs::<Test>::get(hash),
+            Some(ClaimInfo {
+                owner: 1,
+                block_number: 1,
+                timestamp: 0,
+            })
+        );
+        assert_eq!(HashProofs::<Test>::count(), 1);
+        assert_eq!(ClaimCount::<Test>::get(1), 1);
+        assert_eq!(StorageVersion::get::<PoeModule>(), 4);
+    })
+}
+
+fn claims(items: &[&[u8]]) -> BoundedVec<BoundedVec<u8, ConstU32<10>>, ConstU32<5>> {
+    items
+        .iter()
+        .map(|item| BoundedVec::try_from(item.to_vec()).unwrap())
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn create_claims_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(PoeModule::create_claims(
+            RuntimeOrigin::signed(1),
+            claims(&[&[1], &[2], &[3]]),
+            true
+        ));
+
+        assert_eq!(ClaimCount::<Test>::get(1), 3);
+        System::assert_last_event(Event::<Test>::BatchCompleted(3, 0).into());
+    })
+}
+
+#[test]
+fn atomic_batch_reverts_on_failure() {
+    new_test_ext().execute_with(|| {
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(2), claims(&[&[2]])[0].clone(), None);
+
+        assert_noop!(
+            PoeModule::create_claims(RuntimeOrigin::signed(1), claims(&[&[1], &[2], &[3]]), true),
+            Error::<Test>::ProofAlreadyExist
+        );
+        assert_eq!(ClaimCount::<Test>::get(1), 0);
+    })
+}
+
+#[test]
+fn best_effort_batch_skips_failures() {
+    new_test_ext().execute_with(|| {
+        let taken = claims(&[&[2]])[0].clone();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(2), taken.clone(), None);
+
+        assert_ok!(PoeModule::create_claims(
+            RuntimeOrigin::signed(1),
+            claims(&[&[1], &[2], &[3]]),
+            false
+        ));
+
+        assert_eq!(ClaimCount::<Test>::get(1), 2);
+        assert_eq!(Proofs::<Test>::get(&taken).map(|info| info.owner), Some(2));
+        System::assert_has_event(
+            Event::<Test>::BatchItemFailed(taken, Error::<Test>::ProofAlreadyExist.into()).into(),
+        );
+        System::assert_last_event(Event::<Test>::BatchCompleted(2, 1).into());
+    })
+}
+
+#[test]
+fn revoke_claims_works() {
+    new_test_ext().execute_with(|| {
+        let _ = PoeModule::create_claims(RuntimeOrigin::signed(1), claims(&[&[1], &[2]]), true);
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(2), claims(&[&[3]])[0].clone(), None);
+
+        // Claim 3 belongs to someone else.
+        assert_noop!(
+            PoeModule::revoke_claims(RuntimeOrigin::signed(1), claims(&[&[1], &[2], &[3]]), true),
+            Error::<Test>::NotClaimOwner
+        );
+
+        assert_ok!(PoeModule::revoke_claims(
+            RuntimeOrigin::signed(1),
+            claims(&[&[1], &[2], &[3]]),
+            false
+        ));
+        assert_eq!(ClaimCount::<Test>::get(1), 0);
+        assert_eq!(ClaimCount::<Test>::get(2), 1);
+        assert_eq!(Balances::free_balance(1), 100);
+        System::assert_last_event(Event::<Test>::BatchCompleted(2, 1).into());
+    })
+}
+
+#[test]
+fn transfer_claims_works() {
+    new_test_ext().execute_with(|| {
+        let _ = PoeModule::create_claims(RuntimeOrigin::signed(1), claims(&[&[1], &[2]]), true);
+
+        assert_ok!(PoeModule::transfer_claims(
+            RuntimeOrigin::signed(1),
+            claims(&[&[1], &[2]]),
+            3,
+            true
+        ));
+
+        assert_eq!(ClaimCount::<Test>::get(1), 0);
+        assert_eq!(ClaimCount::<Test>::get(3), 2);
+
+        AllowDirectTransfer::set(false);
+        assert_noop!(
+            PoeModule::transfer_claims(RuntimeOrigin::signed(3), claims(&[&[1]]), 1, false),
+            Error::<Test>::DirectTransferDisabled
+        );
+    })
+}
+
+#[test]
+fn create_claim_with_content_indexes_content() {
+    let content: BoundedVec<u8, ConstU32<64>> = b"the full document".to_vec().try_into().unwrap();
+    let hash = PoeModule::hash_of(&content);
+
+    let mut ext = new_test_ext();
+    ext.execute_with(|| {
+        assert_ok!(PoeModule::create_claim_with_content(
+            RuntimeOrigin::signed(1),
+            content.clone()
+        ));
+
+        assert_eq!(
+            HashProofs::<Test>::get(hash).map(|info| info.owner),
+            Some(1)
+        );
+        System::assert_last_event(Event::<Test>::HashClaimCreated(1, hash, 0).into());
+
+        assert_noop!(
+            PoeModule::create_claim_with_content(RuntimeOrigin::signed(2), content.clone()),
+            Error::<Test>::ProofAlreadyExist
+        );
+    });
+    ext.persist_offchain_overlay();
+
+    assert_eq!(
+        ext.offchain_db().get(&offchain_content_key(&hash)),
+        Some(content.into_inner())
+    );
+}
+
+fn set_up_nft_collection() {
+    assert_ok!(Nfts::force_create(
+        RuntimeOrigin::root(),
+        3,
+        pallet_nfts::CollectionConfig {
+            settings: pallet_nfts::CollectionSettings::all_enabled(),
+            max_supply: None,
+            mint_settings: pallet_nfts::MintSettings::default(),
+        }
+    ));
+    assert_ok!(PoeModule::set_nft_collection(
+        RuntimeOrigin::root(),
+        Some(0)
+    ));
+}
+
+fn nft_owner(item: u32) -> Option<u64> {
+    <Nfts as NftInspect<u64>>::owner(&0, &item)
+}
+
+#[test]
+fn claims_are_mirrored_by_nfts() {
+    new_test_ext().execute_with(|| {
+        set_up_nft_collection();
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+
+        assert_ok!(PoeModule::create_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            None
+        ));
+        assert_eq!(ClaimNfts::<Test>::get(&claim), Some((0, 0)));
+        assert_eq!(nft_owner(0), Some(1));
+        System::assert_has_event(Event::<Test>::ClaimNftMinted(claim.clone(), 0, 0).into());
+
+        assert_ok!(PoeModule::transfer_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            2
+        ));
+        assert_eq!(nft_owner(0), Some(2));
+
+        assert_ok!(PoeModule::revoke_claim(
+            RuntimeOrigin::signed(2),
+            claim.clone()
+        ));
+        assert_eq!(ClaimNfts::<Test>::get(&claim), None);
+        assert_eq!(nft_owner(0), None);
+
+        // Item IDs are not reused.
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        assert_eq!(ClaimNfts::<Test>::get(&claim), Some((0, 1)));
+    })
+}
+
+#[test]
+fn claim_nft_cannot_be_traded_on_its_own() {
+    new_test_ext().execute_with(|| {
+        set_up_nft_collection();
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+
+        assert_noop!(
+            Nfts::transfer(RuntimeOrigin::signed(1), 0, 0, 3),
+            pallet_nfts::Error::<Test>::ItemLocked
+        );
+
+        // The NFT moves with the claim and stays locked afterwards.
+        assert_ok!(PoeModule::transfer_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            2
+        ));
+        assert_eq!(nft_owner(0), Some(2));
+        assert_noop!(
+            Nfts::transfer(RuntimeOrigin::signed(2), 0, 0, 3),
+            pallet_nfts::Error::<Test>::ItemLocked
+        );
+
+        // A claim whose NFT was burnt can still be revoked.
+        assert_ok!(Nfts::burn(RuntimeOrigin::signed(2), 0, 0));
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim));
+    })
+}
+
+#[test]
+fn forced_changes_detach_an_nft_that_cannot_follow() {
+    new_test_ext().execute_with(|| {
+        set_up_nft_collection();
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+        assert_ok!(Nfts::lock_collection(
+            RuntimeOrigin::signed(3),
+            0,
+            pallet_nfts::CollectionSettings::from_disabled(
+                pallet_nfts::CollectionSetting::TransferableItems.into()
+            )
+        ));
+
+        assert_noop!(
+            PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2),
+            pallet_nfts::Error::<Test>::ItemsNonTransferable
+        );
+
+        assert_ok!(PoeModule::force_transfer_claim(
+            RuntimeOrigin::root(),
+            claim.clone(),
+            2,
+            reason(b"fraud")
+        ));
+        assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(2));
+        assert_eq!(ClaimNfts::<Test>::get(&claim), None);
+        assert_eq!(nft_owner(0), Some(1));
+        System::assert_has_event(
+            Event::<Test>::ClaimNftDetached(
+                claim.clone(),
+                pallet_nfts::Error::<Test>::ItemsNonTransferable.into(),
+            )
+            .into(),
+        );
+
+        // The detached NFT is left alone from then on.
+        assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim));
+        assert_eq!(nft_owner(0), Some(1));
+    })
+}
+
+#[test]
+fn instances_sharing_a_collection_mint_separate_ids() {
+    new_test_ext().execute_with(|| {
+        set_up_nft_collection();
+        assert_ok!(PoeArtifacts::set_nft_collection(
+            RuntimeOrigin::root(),
+            Some(0)
+        ));
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        let artifact = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+
+        assert_ok!(PoeModule::create_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            None
+        ));
+        assert_ok!(PoeArtifacts::create_claim(
+            RuntimeOrigin::signed(2),
+            artifact.clone(),
+            None
+        ));
+
+        assert_eq!(ClaimNfts::<Test>::get(&claim), Some((0, 0)));
+        assert_eq!(
+            ClaimNfts::<Test, Instance1>::get(&artifact),
+            Some((0, 1_000))
+        );
+        assert_eq!(nft_owner(1_000), Some(2));
+    })
+}
+
+#[test]
+fn no_nft_is_minted_without_a_collection() {
+    new_test_ext().execute_with(|| {
+        let claim = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        assert_ok!(PoeModule::create_claim(
+            RuntimeOrigin::signed(1),
+            claim.clone(),
+            None
+        ));
+        assert_eq!(ClaimNfts::<Test>::get(&claim), None);
+
+        assert_noop!(
+            PoeModule::set_nft_collection(RuntimeOrigin::signed(1), Some(0)),
+            DispatchError::BadOrigin
+        );
+    })
+}
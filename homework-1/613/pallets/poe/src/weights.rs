@@ -0,0 +1,420 @@
+//! Placeholder weights for pallet_poe.
+//!
+//! These numbers are not benchmark results. They are hand estimates from the storage each call
+//! accesses, with proof sizes derived from the bounds of each instance, and should be replaced
+//! once the benchmarks in `benchmarking.rs` have been run on reference hardware.
+
+use codec::MaxEncodedLen;
+use core::marker::PhantomData;
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+    BoundedVec,
+};
+
+/// Weight functions needed for pallet_poe.
+pub trait WeightInfo {
+    fn create_claim() -> Weight;
+    fn revoke_claim() -> Weight;
+    fn transfer_claim() -> Weight;
+    fn create_hash_claim() -> Weight;
+    fn revoke_hash_claim() -> Weight;
+    fn transfer_hash_claim() -> Weight;
+    fn offer_claim() -> Weight;
+    fn accept_claim() -> Weight;
+    fn cancel_offer() -> Weight;
+    fn set_claim_metadata() -> Weight;
+    fn set_hash_claim_metadata() -> Weight;
+    fn create_merkle_claim() -> Weight;
+    fn verify_inclusion(p: u32) -> Weight;
+    fn create_claim_signed() -> Weight;
+    fn approve_operator() -> Weight;
+    fn revoke_operator() -> Weight;
+    fn freeze_claim() -> Weight;
+    fn unfreeze_claim() -> Weight;
+    fn force_transfer_claim() -> Weight;
+    fn force_revoke_claim() -> Weight;
+    fn create_claims(n: u32) -> Weight;
+    fn revoke_claims(n: u32) -> Weight;
+    fn transfer_claims(n: u32) -> Weight;
+    fn create_claim_with_content(b: u32) -> Weight;
+    fn set_nft_collection() -> Weight;
+    fn on_initialize(n: u32) -> Weight;
+    fn freeze_hash_claim() -> Weight;
+    fn unfreeze_hash_claim() -> Weight;
+    fn force_transfer_hash_claim() -> Weight;
+    fn force_revoke_hash_claim() -> Weight;
+}
+
+/// Placeholder weights for the runtime. The proof sizes grow with the bounds of the instance `I`.
+pub struct SubstrateWeight<T, I = ()>(PhantomData<(T, I)>);
+impl<T: crate::Config<I>, I: 'static> SubstrateWeight<T, I> {
+    /// The largest proof of `n` entries keyed by a claim.
+    fn claim_keys(n: u64) -> u64 {
+        let key = 48 + BoundedVec::<u8, T::MaxClaimLenth>::max_encoded_len() as u64;
+        key.saturating_mul(n)
+    }
+    /// The largest metadata of a claim.
+    fn metadata() -> u64 {
+        crate::ClaimMetadata::<T::MaxMetadataLength>::max_encoded_len() as u64
+    }
+    /// The largest ownership history of a claim.
+    fn history() -> u64 {
+        BoundedVec::<crate::ProvenanceRecordOf<T>, T::MaxHistoryLength>::max_encoded_len() as u64
+    }
+    /// The largest proof of everything stored under a claim.
+    fn claim_proof() -> u64 {
+        Self::claim_keys(10)
+            .saturating_add(Self::metadata())
+            .saturating_add(Self::history())
+    }
+    /// The largest proof of everything stored under a hash claim beyond its fixed-size entries.
+    fn hash_claim_proof() -> u64 {
+        Self::metadata().saturating_add(Self::history())
+    }
+}
+impl<T: crate::Config<I>, I: 'static> WeightInfo for SubstrateWeight<T, I> {
+    fn create_claim() -> Weight {
+        Weight::from_parts(70_000_000, 12_083)
+            .saturating_add(Weight::from_parts(0, Self::claim_proof()))
+            .saturating_add(T::DbWeight::get().reads(12_u64))
+            .saturating_add(T::DbWeight::get().writes(16_u64))
+    }
+    fn revoke_claim() -> Weight {
+        Weight::from_parts(75_000_000, 12_083)
+            .saturating_add(Weight::from_parts(0, Self::claim_proof()))
+            .saturating_add(T::DbWeight::get().reads(15_u64))
+            .saturating_add(T::DbWeight::get().writes(20_u64))
+    }
+    fn transfer_claim() -> Weight {
+        Weight::from_parts(80_000_000, 14_127)
+            .saturating_add(Weight::from_parts(0, Self::claim_proof()))
+            .saturating_add(T::DbWeight::get().reads(17_u64))
+            .saturating_add(T::DbWeight::get().writes(18_u64))
+    }
+    fn create_hash_claim() -> Weight {
+        Weight::from_parts(40_000_000, 7_569)
+            .saturating_add(Weight::from_parts(0, Self::history()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    fn revoke_hash_claim() -> Weight {
+        Weight::from_parts(45_000_000, 7_569)
+            .saturating_add(Weight::from_parts(0, Self::hash_claim_proof()))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+    fn transfer_hash_claim() -> Weight {
+        Weight::from_parts(50_000_000, 7_569)
+            .saturating_add(Weight::from_parts(0, Self::hash_claim_proof()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+    fn offer_claim() -> Weight {
+        Weight::from_parts(15_000_000, 3_593)
+            .saturating_add(Weight::from_parts(0, Self::claim_keys(2)))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn accept_claim() -> Weight {
+        Weight::from_parts(80_000_000, 14_127)
+            .saturating_add(Weight::from_parts(0, Self::claim_proof()))
+            .saturating_add(T::DbWeight::get().reads(16_u64))
+            .saturating_add(T::DbWeight::get().writes(17_u64))
+    }
+    fn cancel_offer() -> Weight {
+        Weight::from_parts(15_000_000, 3_593)
+            .saturating_add(Weight::from_parts(0, Self::claim_keys(2)))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn set_claim_metadata() -> Weight {
+        Weight::from_parts(18_000_000, 3_593)
+            .saturating_add(Weight::from_parts(
+                0,
+                Self::claim_keys(2).saturating_add(Self::metadata()),
+            ))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn set_hash_claim_metadata() -> Weight {
+        Weight::from_parts(17_000_000, 3_509)
+            .saturating_add(Weight::from_parts(0, Self::metadata()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn create_merkle_claim() -> Weight {
+        Weight::from_parts(42_000_000, 7_569)
+            .saturating_add(Weight::from_parts(0, Self::history()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+    fn verify_inclusion(p: u32) -> Weight {
+        Weight::from_parts(12_000_000, 3_509)
+            .saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+    fn create_claim_signed() -> Weight {
+        Weight::from_parts(135_000_000, 15_676)
+            .saturating_add(Weight::from_parts(0, Self::claim_proof()))
+            .saturating_add(T::DbWeight::get().reads(13_u64))
+            .saturating_add(T::DbWeight::get().writes(17_u64))
+    }
+    fn approve_operator() -> Weight {
+        Weight::from_parts(15_000_000, 3_593)
+            .saturating_add(Weight::from_parts(0, Self::claim_keys(1)))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn revoke_operator() -> Weight {
+        Weight::from_parts(15_000_000, 3_593)
+            .saturating_add(Weight::from_parts(0, Self::claim_keys(1)))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn freeze_claim() -> Weight {
+        Weight::from_parts(14_000_000, 3_509)
+            .saturating_add(Weight::from_parts(0, Self::claim_keys(2)))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn unfreeze_claim() -> Weight {
+        Weight::from_parts(13_000_000, 0)
+            .saturating_add(Weight::from_parts(0, Self::claim_keys(1)))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn force_transfer_claim() -> Weight {
+        Weight::from_parts(80_000_000, 14_127)
+            .saturating_add(Weight::from_parts(0, Self::claim_proof()))
+            .saturating_add(T::DbWeight::get().reads(14_u64))
+            .saturating_add(T::DbWeight::get().writes(19_u64))
+    }
+    fn force_revoke_claim() -> Weight {
+        Weight::from_parts(75_000_000, 12_083)
+            .saturating_add(Weight::from_parts(0, Self::claim_proof()))
+            .saturating_add(T::DbWeight::get().reads(12_u64))
+            .saturating_add(T::DbWeight::get().writes(20_u64))
+    }
+    fn create_claims(n: u32) -> Weight {
+        Weight::from_parts(10_000_000, 12_083)
+            .saturating_add(Weight::from_parts(0, Self::claim_proof()).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(67_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads((12_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((16_u64).saturating_mul(n.into())))
+    }
+    fn revoke_claims(n: u32) -> Weight {
+        Weight::from_parts(10_000_000, 12_083)
+            .saturating_add(Weight::from_parts(0, Self::claim_proof()).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(72_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads((15_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((20_u64).saturating_mul(n.into())))
+    }
+    fn transfer_claims(n: u32) -> Weight {
+        Weight::from_parts(10_000_000, 14_127)
+            .saturating_add(Weight::from_parts(0, Self::claim_proof()).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(77_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads((17_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((18_u64).saturating_mul(n.into())))
+    }
+    fn create_claim_with_content(b: u32) -> Weight {
+        Weight::from_parts(42_000_000, 7_569)
+            .saturating_add(Weight::from_parts(0, Self::history()))
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    fn set_nft_collection() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn on_initialize(n: u32) -> Weight {
+        Weight::from_parts(5_000_000, 1_489)
+            .saturating_add(Weight::from_parts(70_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, Self::claim_proof()).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((15_u64).saturating_mul(n.into())))
+    }
+    fn freeze_hash_claim() -> Weight {
+        Weight::from_parts(14_000_000, 7_018)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn unfreeze_hash_claim() -> Weight {
+        Weight::from_parts(13_000_000, 3_509)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn force_transfer_hash_claim() -> Weight {
+        Weight::from_parts(52_000_000, 7_569)
+            .saturating_add(Weight::from_parts(0, Self::hash_claim_proof()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(9_u64))
+    }
+    fn force_revoke_hash_claim() -> Weight {
+        Weight::from_parts(47_000_000, 7_569)
+            .saturating_add(Weight::from_parts(0, Self::hash_claim_proof()))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(9_u64))
+    }
+}
+
+/// The same placeholders with `RocksDbWeight`, for tests.
+impl WeightInfo for () {
+    fn create_claim() -> Weight {
+        Weight::from_parts(70_000_000, 12_083)
+            .saturating_add(RocksDbWeight::get().reads(12_u64))
+            .saturating_add(RocksDbWeight::get().writes(16_u64))
+    }
+    fn revoke_claim() -> Weight {
+        Weight::from_parts(75_000_000, 12_083)
+            .saturating_add(RocksDbWeight::get().reads(15_u64))
+            .saturating_add(RocksDbWeight::get().writes(20_u64))
+    }
+    fn transfer_claim() -> Weight {
+        Weight::from_parts(80_000_000, 14_127)
+            .saturating_add(RocksDbWeight::get().reads(17_u64))
+            .saturating_add(RocksDbWeight::get().writes(18_u64))
+    }
+    fn create_hash_claim() -> Weight {
+        Weight::from_parts(40_000_000, 7_569)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    fn revoke_hash_claim() -> Weight {
+        Weight::from_parts(45_000_000, 7_569)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+    fn transfer_hash_claim() -> Weight {
+        Weight::from_parts(50_000_000, 7_569)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+    fn offer_claim() -> Weight {
+        Weight::from_parts(15_000_000, 3_593)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn accept_claim() -> Weight {
+        Weight::from_parts(80_000_000, 14_127)
+            .saturating_add(RocksDbWeight::get().reads(16_u64))
+            .saturating_add(RocksDbWeight::get().writes(17_u64))
+    }
+    fn cancel_offer() -> Weight {
+        Weight::from_parts(15_000_000, 3_593)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn set_claim_metadata() -> Weight {
+        Weight::from_parts(18_000_000, 3_593)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn set_hash_claim_metadata() -> Weight {
+        Weight::from_parts(17_000_000, 3_509)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn create_merkle_claim() -> Weight {
+        Weight::from_parts(42_000_000, 7_569)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    fn verify_inclusion(p: u32) -> Weight {
+        Weight::from_parts(12_000_000, 3_509)
+            .saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+    fn create_claim_signed() -> Weight {
+        Weight::from_parts(135_000_000, 15_676)
+            .saturating_add(RocksDbWeight::get().reads(13_u64))
+            .saturating_add(RocksDbWeight::get().writes(17_u64))
+    }
+    fn approve_operator() -> Weight {
+        Weight::from_parts(15_000_000, 3_593)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn revoke_operator() -> Weight {
+        Weight::from_parts(15_000_000, 3_593)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn freeze_claim() -> Weight {
+        Weight::from_parts(14_000_000, 3_509)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn unfreeze_claim() -> Weight {
+        Weight::from_parts(13_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn force_transfer_claim() -> Weight {
+        Weight::from_parts(80_000_000, 14_127)
+            .saturating_add(RocksDbWeight::get().reads(14_u64))
+            .saturating_add(RocksDbWeight::get().writes(19_u64))
+    }
+    fn force_revoke_claim() -> Weight {
+        Weight::from_parts(75_000_000, 12_083)
+            .saturating_add(RocksDbWeight::get().reads(12_u64))
+            .saturating_add(RocksDbWeight::get().writes(20_u64))
+    }
+    fn create_claims(n: u32) -> Weight {
+        Weight::from_parts(10_000_000, 12_083)
+            .saturating_add(Weight::from_parts(67_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads((12_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes((16_u64).saturating_mul(n.into())))
+    }
+    fn revoke_claims(n: u32) -> Weight {
+        Weight::from_parts(10_000_000, 12_083)
+            .saturating_add(Weight::from_parts(72_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads((15_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes((20_u64).saturating_mul(n.into())))
+    }
+    fn transfer_claims(n: u32) -> Weight {
+        Weight::from_parts(10_000_000, 14_127)
+            .saturating_add(Weight::from_parts(77_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads((17_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes((18_u64).saturating_mul(n.into())))
+    }
+    fn create_claim_with_content(b: u32) -> Weight {
+        Weight::from_parts(42_000_000, 7_569)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    fn set_nft_collection() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn on_initialize(n: u32) -> Weight {
+        Weight::from_parts(5_000_000, 1_489)
+            .saturating_add(Weight::from_parts(70_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().reads((9_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((15_u64).saturating_mul(n.into())))
+    }
+    fn freeze_hash_claim() -> Weight {
+        Weight::from_parts(14_000_000, 7_018)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn unfreeze_hash_claim() -> Weight {
+        Weight::from_parts(13_000_000, 3_509)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn force_transfer_hash_claim() -> Weight {
+        Weight::from_parts(52_000_000, 7_569)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(9_u64))
+    }
+    fn force_revoke_hash_claim() -> Weight {
+        Weight::from_parts(47_000_000, 7_569)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(9_u64))
+    }
+}
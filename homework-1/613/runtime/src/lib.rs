@@ -18,12 +18,11 @@ use sp_std::prelude::*;
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
-use frame_support::genesis_builder_helper::{build_config, create_default_config};
 pub use frame_support::{
     construct_runtime, derive_impl, parameter_types,
     traits::{
-        ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
-        StorageInfo,
+        AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8,
+        KeyOwnerProofSystem, Randomness, StorageInfo,
     },
     weights::{
         constants::{
@@ -33,6 +32,11 @@ pub use frame_support::{
     },
     StorageValue,
 };
+use frame_support::{
+    genesis_builder_helper::{build_config, create_default_config},
+    traits::{Get, PalletInfoAccess},
+    BoundedVec, PalletId,
+};
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
@@ -99,10 +103,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 100,
+    spec_version: 101,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     state_version: 1,
 };
 
@@ -223,7 +227,7 @@ impl pallet_balances::Config for Runtime {
     type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
     type FreezeIdentifier = ();
     type MaxFreezes = ();
-    type RuntimeHoldReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
     type RuntimeFreezeReason = ();
 }
 
@@ -252,9 +256,198 @@ impl pallet_template::Config for Runtime {
     type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
 }
 
-impl pallet_poe::Config for Runtime {
+parameter_types! {
+    pub const NftCollectionDeposit: Balance = 100 * EXISTENTIAL_DEPOSIT;
+    pub const NftItemDeposit: Balance = EXISTENTIAL_DEPOSIT;
+    pub const NftMetadataDepositBase: Balance = 10 * EXISTENTIAL_DEPOSIT;
+    pub const NftAttributeDepositBase: Balance = 10 * EXISTENTIAL_DEPOSIT;
+    pub const NftDepositPerByte: Balance = EXISTENTIAL_DEPOSIT / 10;
+    pub const MaxNftDeadlineDuration: BlockNumber = 365 * DAYS;
+    pub NftFeatures: pallet_nfts::PalletFeatures = pallet_nfts::PalletFeatures::all_enabled();
+}
+
+impl pallet_nfts::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type Locker = ();
+    type CollectionDeposit = NftCollectionDeposit;
+    type ItemDeposit = NftItemDeposit;
+    type MetadataDepositBase = NftMetadataDepositBase;
+    type AttributeDepositBase = NftAttributeDepositBase;
+    type DepositPerByte = NftDepositPerByte;
+    type StringLimit = ConstU32<256>;
+    type KeyLimit = ConstU32<64>;
+    type ValueLimit = ConstU32<256>;
+    type ApprovalsLimit = ConstU32<20>;
+    type ItemAttributesApprovalsLimit = ConstU32<30>;
+    type MaxTips = ConstU32<10>;
+    type MaxDeadlineDuration = MaxNftDeadlineDuration;
+    type MaxAttributesPerCall = ConstU32<10>;
+    type Features = NftFeatures;
+    type OffchainSignature = Signature;
+    type OffchainPublic = <Signature as Verify>::Signer;
+    type WeightInfo = pallet_nfts::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+}
+
+/// The registry of legal documents.
+type PoeLegalInstance = pallet_poe::Instance1;
+/// The registry of code artefacts.
+type PoeArtifactsInstance = pallet_poe::Instance2;
+
+parameter_types! {
+    pub const LegalClaimDepositBase: Balance = 10 * EXISTENTIAL_DEPOSIT;
+    pub const LegalClaimDepositPerByte: Balance = EXISTENTIAL_DEPOSIT;
+    pub const ArtifactClaimDepositBase: Balance = EXISTENTIAL_DEPOSIT;
+    pub const ArtifactClaimDepositPerByte: Balance = EXISTENTIAL_DEPOSIT / 10;
+    pub const MaxArtifactClaims: Option<u32> = Some(1_000_000);
+    // Claim NFTs carry metadata like any other item. `pallet_poe` locks them so that they only
+    // change hands with their claim.
+    pub ClaimNftConfig: pallet_nfts::ItemConfig = pallet_nfts::ItemConfig {
+        settings: pallet_nfts::ItemSettings::all_enabled(),
+    };
+}
+
+impl pallet_poe::Config<PoeLegalInstance> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
     type MaxClaimLenth = ConstU32<10>;
+    type UnixTime = Timestamp;
+    type ClaimDepositBase = LegalClaimDepositBase;
+    type ClaimDepositPerByte = LegalClaimDepositPerByte;
+    type AllowDirectTransfer = ConstBool<true>;
+    type MaxExpiriesPerBlock = ConstU32<100>;
+    type MaxMetadataLength = ConstU32<128>;
+    type MaxHistoryLength = ConstU32<32>;
+    type MaxMerkleProofLength = ConstU32<32>;
+    type OffchainSignature = Signature;
+    type OffchainPublic = <Signature as Verify>::Signer;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxReasonLength = ConstU32<128>;
+    type MaxClaimsPerAccount = ConstU32<1_000>;
+    type MaxTotalClaims = ();
+    type MaxBatchSize = ConstU32<100>;
+    type MaxContentLength = ConstU32<{ 256 * 1024 }>;
+    type NftCollectionId = u32;
+    type NftId = u32;
+    type FirstNftId = ConstU32<0>;
+    type NftItemConfig = pallet_nfts::ItemConfig;
+    type ClaimNftConfig = ClaimNftConfig;
+    type Nfts = Nfts;
+    type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime, PoeLegalInstance>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = PoeBenchmarkHelper;
+}
+
+impl pallet_poe::Config<PoeArtifactsInstance> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type MaxClaimLenth = ConstU32<256>;
+    type UnixTime = Timestamp;
+    type ClaimDepositBase = ArtifactClaimDepositBase;
+    type ClaimDepositPerByte = ArtifactClaimDepositPerByte;
+    type AllowDirectTransfer = ConstBool<true>;
+    type MaxExpiriesPerBlock = ConstU32<100>;
+    type MaxMetadataLength = ConstU32<128>;
+    type MaxHistoryLength = ConstU32<32>;
+    type MaxMerkleProofLength = ConstU32<32>;
+    type OffchainSignature = Signature;
+    type OffchainPublic = <Signature as Verify>::Signer;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxReasonLength = ConstU32<128>;
+    type MaxClaimsPerAccount = ConstU32<10_000>;
+    type MaxTotalClaims = MaxArtifactClaims;
+    type MaxBatchSize = ConstU32<100>;
+    type MaxContentLength = ConstU32<{ 256 * 1024 }>;
+    type NftCollectionId = u32;
+    type NftId = u32;
+    // Both registries may mint into one collection, so they use separate halves of it.
+    type FirstNftId = ConstU32<{ 1 << 31 }>;
+    type NftItemConfig = pallet_nfts::ItemConfig;
+    type ClaimNftConfig = ClaimNftConfig;
+    type Nfts = Nfts;
+    type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime, PoeArtifactsInstance>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = PoeBenchmarkHelper;
+}
+
+/// Signs claim payloads with sr25519 keys and creates NFT collections in the PoE benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct PoeBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_poe::BenchmarkHelper<<Signature as Verify>::Signer, AccountId, Signature, u32>
+    for PoeBenchmarkHelper
+{
+    fn signer() -> (<Signature as Verify>::Signer, AccountId) {
+        <() as pallet_poe::BenchmarkHelper<_, _, Signature, u32>>::signer()
+    }
+
+    fn sign(signer: &<Signature as Verify>::Signer, message: &[u8]) -> Signature {
+        <() as pallet_poe::BenchmarkHelper<_, AccountId, _, u32>>::sign(signer, message)
+    }
+
+    fn nft_collection() -> Option<u32> {
+        use frame_support::traits::{fungible::Mutate, tokens::nonfungibles_v2::Create};
+
+        let owner: AccountId = frame_benchmarking::account("nft_owner", 0, 0);
+        Balances::set_balance(&owner, 1_000 * NftCollectionDeposit::get());
+        let config = pallet_nfts::CollectionConfig {
+            settings: pallet_nfts::CollectionSettings::all_enabled(),
+            max_supply: None,
+            mint_settings: pallet_nfts::MintSettings::default(),
+        };
+        <Nfts as Create<AccountId, pallet_nfts::CollectionConfigFor<Runtime>>>::create_collection(
+            &owner, &owner, &config,
+        )
+        .ok()
+    }
+}
+
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
+parameter_types! {
+    pub const CoinflipPalletId: PalletId = PalletId(*b"py/cflip");
+}
+
+impl pallet_coinflip::Config for Runtime {
+    type Randomness = RandomnessCollectiveFlip;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type PalletId = CoinflipPalletId;
+    type HouseOrigin = frame_system::EnsureRoot<AccountId>;
+    type RevealDelay = ConstU32<1>;
+    type RevealPeriod = ConstU32<{ 10 * MINUTES }>;
+    type MaxLeaderboardLength = ConstU32<100>;
+    type WeightInfo = pallet_coinflip::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = CoinflipBenchmarkHelper;
+}
+
+/// Replaces the material of `RandomnessCollectiveFlip` in the coinflip benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CoinflipBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_coinflip::BenchmarkHelper for CoinflipBenchmarkHelper {
+    fn reseed(attempt: u32) {
+        use sp_runtime::traits::Hash;
+
+        // The material is not public, so write it under its storage key.
+        let key = frame_support::storage::storage_prefix(
+            RandomnessCollectiveFlip::name().as_bytes(),
+            b"RandomMaterial",
+        );
+        frame_support::storage::unhashed::put(&key, &vec![BlakeTwo256::hash_of(&attempt)]);
+    }
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -300,7 +493,19 @@ mod runtime {
     pub type TemplateModule = pallet_template;
 
     #[runtime::pallet_index(8)]
-    pub type PoeModule = pallet_poe;
+    pub type PoeLegal = pallet_poe<Instance1>;
+
+    #[runtime::pallet_index(9)]
+    pub type PoeArtifacts = pallet_poe<Instance2>;
+
+    #[runtime::pallet_index(10)]
+    pub type Nfts = pallet_nfts;
+
+    #[runtime::pallet_index(11)]
+    pub type RandomnessCollectiveFlip = pallet_insecure_randomness_collective_flip;
+
+    #[runtime::pallet_index(12)]
+    pub type Coinflip = pallet_coinflip;
 }
 
 /// The address format for describing accounts.
@@ -321,11 +526,77 @@ pub type SignedExtra = (
     pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 );
 
+/// Move the claims registered before `pallet_poe` became instanceable from its `PoeModule`
+/// prefix to the `PoeLegal` registry that replaced it.
+///
+/// `PoeModule` never recorded a storage version, so `PoeLegal` is set to version 0 explicitly
+/// and the pallet migrations bring it up to date from there.
+pub struct MovePoeModuleToPoeLegal;
+
+impl frame_support::traits::OnRuntimeUpgrade for MovePoeModuleToPoeLegal {
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        use codec::Encode;
+        Ok((
+            poe_claim_count(b"PoeModule"),
+            poe_claim_count(PoeLegal::name().as_bytes()),
+        )
+            .encode())
+    }
+
+    fn on_runtime_upgrade() -> Weight {
+        let prefix = sp_core::hashing::twox_128(b"PoeModule");
+        if !frame_support::storage::unhashed::contains_prefixed_key(&prefix) {
+            return <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+        }
+        frame_support::migrations::move_pallet(b"PoeModule", PoeLegal::name().as_bytes());
+        frame_support::traits::StorageVersion::new(0).put::<PoeLegal>();
+
+        // The number of keys moved is unknown, so take the whole block.
+        <Runtime as frame_system::Config>::BlockWeights::get().max_block
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        use codec::Decode;
+        let (moved, kept) = <(u32, u32)>::decode(&mut &state[..])
+            .map_err(|_| sp_runtime::TryRuntimeError::Other("invalid pre-upgrade state"))?;
+        frame_support::ensure!(
+            poe_claim_count(b"PoeModule") == 0,
+            sp_runtime::TryRuntimeError::Other("claims were left under PoeModule")
+        );
+        frame_support::ensure!(
+            poe_claim_count(PoeLegal::name().as_bytes()) == moved.saturating_add(kept),
+            sp_runtime::TryRuntimeError::Other("claims were lost moving PoeModule to PoeLegal")
+        );
+        Ok(())
+    }
+}
+
+/// The number of claims stored under the `Proofs` map of a `pallet_poe` prefix, counted without
+/// decoding them.
+#[cfg(feature = "try-runtime")]
+fn poe_claim_count(pallet: &[u8]) -> u32 {
+    let prefix = frame_support::storage::storage_prefix(pallet, b"Proofs").to_vec();
+    frame_support::storage::KeyPrefixIterator::new(prefix.clone(), prefix, |_| Ok(())).count()
+        as u32
+}
+
 /// All migrations of the runtime, aside from the ones declared in the pallets.
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    MovePoeModuleToPoeLegal,
+    pallet_poe::migrations::v1::MigrateV0ToV1<Runtime, PoeLegalInstance>,
+    pallet_poe::migrations::v1::MigrateV0ToV1<Runtime, PoeArtifactsInstance>,
+    pallet_poe::migrations::v2::MigrateV1ToV2<Runtime, PoeLegalInstance>,
+    pallet_poe::migrations::v2::MigrateV1ToV2<Runtime, PoeArtifactsInstance>,
+    pallet_poe::migrations::v3::MigrateV2ToV3<Runtime, PoeLegalInstance>,
+    pallet_poe::migrations::v3::MigrateV2ToV3<Runtime, PoeArtifactsInstance>,
+    pallet_poe::migrations::v4::MigrateV3ToV4<Runtime, PoeLegalInstance>,
+    pallet_poe::migrations::v4::MigrateV3ToV4<Runtime, PoeArtifactsInstance>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -351,9 +622,158 @@ mod benches {
         [pallet_timestamp, Timestamp]
         [pallet_sudo, Sudo]
         [pallet_template, TemplateModule]
+        [pallet_poe, PoeLegal]
+        [pallet_poe, PoeArtifacts]
+        [pallet_nfts, Nfts]
+        [pallet_coinflip, Coinflip]
     );
 }
 
+/// A PoE registry served by `PoeApi`.
+enum PoeRegistry {
+    Legal,
+    Artifacts,
+}
+
+impl PoeRegistry {
+    /// The registry whose pallet is called `name` in the runtime metadata.
+    fn from_name(name: &[u8]) -> Option<Self> {
+        if name == PoeLegal::name().as_bytes() {
+            Some(Self::Legal)
+        } else if name == PoeArtifacts::name().as_bytes() {
+            Some(Self::Artifacts)
+        } else {
+            None
+        }
+    }
+}
+
+fn poe_claim<I: 'static>(
+    claim: Vec<u8>,
+) -> Option<pallet_poe_runtime_api::ClaimDetails<AccountId, BlockNumber>>
+where
+    Runtime: pallet_poe::Config<I>,
+{
+    let claim = BoundedVec::try_from(claim).ok()?;
+    let info = pallet_poe::Proofs::<Runtime, I>::get(&claim)?;
+    Some(poe_details(
+        info,
+        pallet_poe::Metadata::<Runtime, I>::get(&claim),
+    ))
+}
+
+fn poe_hash_claim<I: 'static>(
+    hash: Hash,
+) -> Option<pallet_poe_runtime_api::ClaimDetails<AccountId, BlockNumber>>
+where
+    Runtime: pallet_poe::Config<I>,
+{
+    let info = pallet_poe::HashProofs::<Runtime, I>::get(&hash)?;
+    Some(poe_details(
+        info,
+        pallet_poe::HashMetadata::<Runtime, I>::get(&hash),
+    ))
+}
+
+fn poe_claim_history<I: 'static>(
+    claim: Vec<u8>,
+) -> Vec<pallet_poe_runtime_api::ProvenanceRecord<AccountId, BlockNumber>>
+where
+    Runtime: pallet_poe::Config<I>,
+{
+    match BoundedVec::try_from(claim) {
+        Ok(claim) => pallet_poe::History::<Runtime, I>::get(&claim)
+            .into_iter()
+            .map(poe_record)
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+fn poe_hash_claim_history<I: 'static>(
+    hash: Hash,
+) -> Vec<pallet_poe_runtime_api::ProvenanceRecord<AccountId, BlockNumber>>
+where
+    Runtime: pallet_poe::Config<I>,
+{
+    pallet_poe::HashHistory::<Runtime, I>::get(&hash)
+        .into_iter()
+        .map(poe_record)
+        .collect()
+}
+
+fn poe_claims_of<I: 'static>(
+    owner: AccountId,
+    start_after: Option<Vec<u8>>,
+    limit: u32,
+) -> (Vec<Vec<u8>>, Option<Vec<u8>>)
+where
+    Runtime: pallet_poe::Config<I>,
+{
+    let start_after = match start_after.map(BoundedVec::try_from) {
+        Some(Ok(claim)) => Some(claim),
+        Some(Err(_)) => return (Vec::new(), None),
+        None => None,
+    };
+    let (claims, next) = pallet_poe::Pallet::<Runtime, I>::claims_of(&owner, start_after, limit);
+    (
+        claims.into_iter().map(BoundedVec::into_inner).collect(),
+        next.map(BoundedVec::into_inner),
+    )
+}
+
+fn poe_details<MaxLength: Get<u32>>(
+    info: pallet_poe::ClaimInfo<AccountId, BlockNumber>,
+    metadata: Option<pallet_poe::ClaimMetadata<MaxLength>>,
+) -> pallet_poe_runtime_api::ClaimDetails<AccountId, BlockNumber> {
+    pallet_poe_runtime_api::ClaimDetails {
+        owner: info.owner,
+        block_number: info.block_number,
+        // Genesis claims and claims migrated from before timestamps were recorded have a zero
+        // timestamp.
+        timestamp: Some(info.timestamp).filter(|timestamp| *timestamp != 0),
+        metadata: metadata.map(|metadata| pallet_poe_runtime_api::ClaimMetadata {
+            content_type: metadata.content_type.into_inner(),
+            uri: metadata.uri.into_inner(),
+            description: metadata.description.into_inner(),
+        }),
+    }
+}
+
+fn poe_record(
+    record: pallet_poe::ProvenanceRecord<AccountId, BlockNumber>,
+) -> pallet_poe_runtime_api::ProvenanceRecord<AccountId, BlockNumber> {
+    use pallet_poe_runtime_api::ClaimAction;
+    let action = match record.action {
+        pallet_poe::ClaimAction::Created => ClaimAction::Created,
+        pallet_poe::ClaimAction::Transferred => ClaimAction::Transferred,
+        pallet_poe::ClaimAction::Revoked => ClaimAction::Revoked,
+        pallet_poe::ClaimAction::Expired => ClaimAction::Expired,
+    };
+    pallet_poe_runtime_api::ProvenanceRecord {
+        owner: record.owner,
+        block_number: record.block_number,
+        action,
+    }
+}
+
+fn coinflip_stats(
+    stats: pallet_coinflip::PlayerStats<Balance>,
+) -> pallet_coinflip_runtime_api::PlayerStats<Balance> {
+    let pallet_coinflip::PlayerStats {
+        games_played,
+        wins,
+        total_staked,
+        total_won,
+    } = stats;
+    pallet_coinflip_runtime_api::PlayerStats {
+        games_played,
+        wins,
+        total_staked,
+        total_won,
+    }
+}
+
 impl_runtime_apis! {
     impl sp_api::Core<Block> for Runtime {
         fn version() -> RuntimeVersion {
@@ -522,6 +942,89 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_poe_runtime_api::PoeApi<Block, AccountId, BlockNumber, Hash> for Runtime {
+        fn get_claim(registry: Vec<u8>, claim: Vec<u8>) -> Option<pallet_poe_runtime_api::ClaimDetails<AccountId, BlockNumber>> {
+            match PoeRegistry::from_name(&registry)? {
+                PoeRegistry::Legal => poe_claim::<PoeLegalInstance>(claim),
+                PoeRegistry::Artifacts => poe_claim::<PoeArtifactsInstance>(claim),
+            }
+        }
+
+        fn get_hash_claim(registry: Vec<u8>, hash: Hash) -> Option<pallet_poe_runtime_api::ClaimDetails<AccountId, BlockNumber>> {
+            match PoeRegistry::from_name(&registry)? {
+                PoeRegistry::Legal => poe_hash_claim::<PoeLegalInstance>(hash),
+                PoeRegistry::Artifacts => poe_hash_claim::<PoeArtifactsInstance>(hash),
+            }
+        }
+
+        fn claim_history(registry: Vec<u8>, claim: Vec<u8>) -> Vec<pallet_poe_runtime_api::ProvenanceRecord<AccountId, BlockNumber>> {
+            match PoeRegistry::from_name(&registry) {
+                Some(PoeRegistry::Legal) => poe_claim_history::<PoeLegalInstance>(claim),
+                Some(PoeRegistry::Artifacts) => poe_claim_history::<PoeArtifactsInstance>(claim),
+                None => Vec::new(),
+            }
+        }
+
+        fn hash_claim_history(registry: Vec<u8>, hash: Hash) -> Vec<pallet_poe_runtime_api::ProvenanceRecord<AccountId, BlockNumber>> {
+            match PoeRegistry::from_name(&registry) {
+                Some(PoeRegistry::Legal) => poe_hash_claim_history::<PoeLegalInstance>(hash),
+                Some(PoeRegistry::Artifacts) => poe_hash_claim_history::<PoeArtifactsInstance>(hash),
+                None => Vec::new(),
+            }
+        }
+
+        fn verify_inclusion(registry: Vec<u8>, root: Hash, leaf: Hash, proof: Vec<Hash>) -> bool {
+            match PoeRegistry::from_name(&registry) {
+                Some(PoeRegistry::Legal) => PoeLegal::is_included(root, leaf, &proof),
+                Some(PoeRegistry::Artifacts) => PoeArtifacts::is_included(root, leaf, &proof),
+                None => false,
+            }
+        }
+
+        fn claims_of(
+            registry: Vec<u8>,
+            owner: AccountId,
+            start_after: Option<Vec<u8>>,
+            limit: u32,
+        ) -> (Vec<Vec<u8>>, Option<Vec<u8>>) {
+            match PoeRegistry::from_name(&registry) {
+                Some(PoeRegistry::Legal) => poe_claims_of::<PoeLegalInstance>(owner, start_after, limit),
+                Some(PoeRegistry::Artifacts) => poe_claims_of::<PoeArtifactsInstance>(owner, start_after, limit),
+                None => (Vec::new(), None),
+            }
+        }
+
+        fn hash_claims_of(
+            registry: Vec<u8>,
+            owner: AccountId,
+            start_after: Option<Hash>,
+            limit: u32,
+        ) -> (Vec<Hash>, Option<Hash>) {
+            match PoeRegistry::from_name(&registry) {
+                Some(PoeRegistry::Legal) => PoeLegal::hash_claims_of(&owner, start_after, limit),
+                Some(PoeRegistry::Artifacts) => PoeArtifacts::hash_claims_of(&owner, start_after, limit),
+                None => (Vec::new(), None),
+            }
+        }
+    }
+
+    impl pallet_coinflip_runtime_api::CoinflipApi<Block, AccountId, Balance> for Runtime {
+        fn player_stats(who: AccountId) -> pallet_coinflip_runtime_api::PlayerStats<Balance> {
+            coinflip_stats(Coinflip::stats(who))
+        }
+
+        fn table_stats(table_id: u32, who: AccountId) -> pallet_coinflip_runtime_api::PlayerStats<Balance> {
+            coinflip_stats(Coinflip::table_stats(table_id, who))
+        }
+
+        fn leaderboard() -> Vec<pallet_coinflip_runtime_api::LeaderboardEntry<AccountId, Balance>> {
+            Coinflip::leaderboard()
+                .into_iter()
+                .map(|(account, total_won)| pallet_coinflip_runtime_api::LeaderboardEntry { account, total_won })
+                .collect()
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (